@@ -1,22 +1,33 @@
-use std::collections::HashMap;
-use input;
+use std::{collections::HashMap, str::FromStr};
 
-// const INPUT_PATH: &str = "./data/day_01_test_input.txt";
-const INPUT_PATH: &str = "./data/day_01_puzzle_input.txt";
+const DAY: u32 = 1;
 
-fn main() {
-    let mut left_list: Vec<i32> = Vec::new();
-    let mut right_list: Vec<i32> = Vec::new();
+struct LocationPair {
+    left: i32,
+    right: i32
+}
 
-    input::lines(INPUT_PATH, |pair: &str| {
-        let split: Vec<&str> = pair.split_whitespace().collect();
-        if split.is_empty() {
-            return;
+impl FromStr for LocationPair {
+    type Err = input::ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = input::scan!(s, "{} {}" => i32, i32)?;
+        Ok(LocationPair { left, right })
+    }
+}
+
+fn main() {
+    let pairs = match input::from_args(DAY).and_then(input::parse_lines::<LocationPair>) {
+        Ok(pairs) => pairs,
+        Err(e) => {
+            eprintln!("failed to load location lists: {e}");
+            std::process::exit(1);
         }
+    };
 
-        left_list.push(split[0].parse::<i32>().unwrap());
-        right_list.push(split[1].parse::<i32>().unwrap());
-    });
+    let (left_list, right_list): (Vec<i32>, Vec<i32>) = pairs.iter()
+        .map(|pair| (pair.left, pair.right))
+        .unzip();
 
     let total_distance = distance(left_list.clone(), right_list.clone());
     println!("total distance: {}", total_distance);
@@ -34,12 +45,12 @@ fn distance(mut left_list: Vec<i32>, mut right_list: Vec<i32>) -> i32 {
         let right = right_list.get(i).unwrap();
         total_distance += (left - right).abs();
     }
-    return total_distance;
+    total_distance
 }
 
 fn similarity(left_list: Vec<i32>, right_list: Vec<i32>) -> i32 {
     let mut right_counts: HashMap<i32, i32> = HashMap::new();
-    for (_, right) in right_list.iter().enumerate() {
+    for right in right_list.iter() {
         let count = right_counts.entry(*right).or_insert(0);
         *count += 1;
     }
@@ -48,5 +59,5 @@ fn similarity(left_list: Vec<i32>, right_list: Vec<i32>) -> i32 {
     for left in left_list.iter() {
         total_similarity += left * right_counts.get(left).unwrap_or(&0);
     }
-    return total_similarity;
+    total_similarity
 }
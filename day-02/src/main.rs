@@ -1,22 +1,33 @@
-use std::ops::RangeInclusive;
-use input;
+use std::{ops::RangeInclusive, str::FromStr};
 
-// const INPUT_PATH: &str = "./data/day_02_test_input.txt";
-const INPUT_PATH: &str = "./data/day_02_puzzle_input.txt";
+const DAY: u32 = 2;
 
 const SAFE_RANGE: RangeInclusive<i32> = 1..=3;
 
+struct Report {
+    levels: Vec<i32>
+}
+
+impl FromStr for Report {
+    type Err = input::NumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let levels = input::numbers::<i32>(s).collect::<Result<Vec<i32>, _>>()?;
+        Ok(Report { levels })
+    }
+}
+
 fn main() {
-    let mut safe_count = 0;
-    input::lines(INPUT_PATH, |report| {
-        if report.is_empty() {
-            return;
+    let reports = match input::from_args(DAY).and_then(input::parse_lines::<Report>) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("failed to load reports: {e}");
+            std::process::exit(1);
         }
+    };
 
-        let levels: Vec<i32> = report.split_whitespace()
-            .map(|level| level.parse::<i32>().unwrap())
-            .collect();
-
+    let mut safe_count = 0;
+    for Report { levels } in reports {
         let mut safe = true;
         let bad_level_indexes = get_bad_level_indexes(&levels);
         if !bad_level_indexes.is_empty() {
@@ -40,11 +51,11 @@ fn main() {
         if !safe {
             println!("{}: {:?}", if safe { "SAFE" } else { "UNSAFE" }, levels);
         }
-    });
+    }
     println!("safe count: {}", safe_count);
 }
 
-fn get_bad_level_indexes(levels: &Vec<i32>) -> Vec<usize> {
+fn get_bad_level_indexes(levels: &[i32]) -> Vec<usize> {
     let mut bad_levels: Vec<usize> = Vec::new();
     let mut increase: Option<bool> = None;
     for current in 0..levels.len() - 1 {
@@ -56,13 +67,15 @@ fn get_bad_level_indexes(levels: &Vec<i32>) -> Vec<usize> {
             break;
         }
 
-        if increase.is_none() {
-            increase = Some(delta.is_positive())
-        } else if increase.unwrap() != delta.is_positive() {
-            let previous = current - 1;
-            bad_levels = (previous..=next).collect();
-            break;
+        match increase {
+            None => increase = Some(delta.is_positive()),
+            Some(increasing) if increasing != delta.is_positive() => {
+                let previous = current - 1;
+                bad_levels = (previous..=next).collect();
+                break;
+            },
+            Some(_) => ()
         }
     }
-    return bad_levels;
+    bad_levels
 }
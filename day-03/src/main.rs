@@ -1,32 +1,31 @@
 use regex::Regex;
 
+const DAY: u32 = 3;
+
 fn main() {
+    let program = match input::from_args(DAY).and_then(input::read_to_string) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("failed to load program: {e}");
+            std::process::exit(1);
+        }
+    };
+
     let mut sum: i32 = 0;
-    let re = Regex::new(r"mul\(([0-9][0-9]?[0-9]?),([0-9][0-9]?[0-9]?)\)").unwrap();
 
     let mut enabled = true;
     let instruction_re = Regex::new(r"(do\(\)|don't\(\)|mul\([0-9][0-9]?[0-9]?,[0-9][0-9]?[0-9]?\))").unwrap();
-    for instruction in instruction_re.find_iter(PUZZLE_INPUT).map(|m| m.as_str()) {
+    for instruction in instruction_re.find_iter(&program).map(|m| m.as_str()) {
         if instruction == "do()" {
             enabled = true;
         } else if instruction == "don't()" {
             enabled = false;
         } else if enabled {
-            let (_, [a, b]) = re.captures(instruction).unwrap().extract();
-            let product = a.parse::<i32>().unwrap() * b.parse::<i32>().unwrap();
+            let (a, b) = input::scan!(instruction, "mul({},{})" => i32, i32).unwrap();
+            let product = a * b;
             sum += product;
             println!("{} x {} = {}", a, b, product);
         }
     }
     println!("{}", sum);
 }
-
-// const TEST_INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-// const TEST_INPUT_2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-
-const PUZZLE_INPUT: &str = "from()$&mul(602,165)why()mul(305,64)?(where()-:mul(80,703);)why()*from()%select()mul(290,957)}/?%'from()]mul(851,335)mul(844,49)what()]![from(667,522)]}how()how()mul(938,695)#/)*?@~?@select()mul(706,392)^mul(242,513)who()@]mul(871,132)( ] from()$mul(41,656)^{(&how()from()[when()-when()mul(628,478)mul(287,621)why():'mul(127,825)# ]mul(360,578)select()?^^select() @:why()mul(136,849);{how()%*@%+how()[mul(544,891)mul(436,21)from()}mul(184,294)+/from(81,871) how()&?]mul(297,492)select()/}%;$$~{*mul(161,703)when():+(,mul(335,695) ()&where()?who();'mul(689,420)^how()from()how()what()>]where()don't()}%mul(78,176)~$where():@,>%mul(62,695)@{#how()mul(742,538)who(){$$who()@{+(mul(282,209)%mul(428,514)/+from()}>@<mul(131,844)mul(431,946)&mul(171,87)+mul(164,733)mul(760,223)/^$*@?[mul(485,824)!  *(^-^;mul(465,327)@}when()+~&$?[%don't()$:/!]mul(249,931)mul(963,891)}$%>mul(665,815),mul(762,48)[+/mul(591,445)~?who()#mul(36,3)select()~$ [<}*/do()''>~'$mul(109,905)}how(){!@where()%/mul(380,929)*){~:where()mul(547,552)$:*mul(58,881)where(145,89)[why(634,213)mul(699,443)mul(826,660)what()from()-how()}'from()what()<;mul(700,665)] #(*?how()<+mul(507,719))~*/-^(%mul(941,551)$;don't()#;:when()+#>mul(87,663)who()/how()mul(332,238)~mul(290,235)',why()mul(950,204);where(),(when()mul(233,633)-^}@who()^mul(289,147)$>-'$select(736,261)*~]mul(899,836)'why()do()@when()why()from()mul(975,883);select()who()mul(165,847)why();^/{^mul(31,153)?;'don't()( <!from()from()~'(&mul(352,285)mul(912,583)<mul(192,230)/+~}<!mul(710,930)-how(461,167)][^why()do()*#mul(543,422)^*from()/^#~do()mul(832,873)]#when(455,108)^}who()/]mul(448,716)why()#/&mul(319,598)when()]{/ /'why()mul(865,894): +what()>from()+})what()mul(262,509)!*where()}/-#~/mul(308,953);*!]mul(741,76)[mul(256,536)[)>%-who()<^[$mul(382,338)where()[ when():select()<>mul(993,630)@$mul(411,640)/+where(623,926)/~mul(807,676)+*],mul(579,979who()what()from()when()how()what(),mul(882,434)&who()'mul(154,824)from()#<<,,{where()$mul(259,438)from()}?:;mul(641,967?from())<,>~mul(325,912)%select()mul where()do()where()where();(!,[$why()mul(131,307)mul(323,794)mul(570,548+!$from()!}what(588,195)<select()select()mul(622,782)why()mul(304,116)${(mul(942,260why()[?<$mul(420,225);~,>-from()mul(319,524)!/why()mul(737,137)+^&]/mul(228,919)@mul(672,604)+when(773,127)select()+why(941,245)mul(121!<[?$?;from(987,370):mul(904,487)#^what()from()({?mul(791,791)!,::)@^(mul(517,686)what()*how(),why()&mul(218,978)#,who()&$who()how(205,211)where()[mul(70,357)^$[?^$^what()mul(245,237)where()why()?{/mul$when()+},why()mul(264,30)@*:when(628,215):+&why()[mul(346,794){:}~-,select(),mul(115,926)~mul(140,56)[/:#~don't()@</mul(750,438)}::]!*!mul(64,9)}#  ^mul(827,948)where(885,400)&mul(795,610)@mul(302,983)>:mul(889,788);;*!<$[when(),%mul(177,883)[mul(815,344)when()@$+ @)where(935,128)}{mul(638,520)why()-] when()] mul(639,171)!>[mulwho()~^:what()why()] <what() mul(595,754);mul(85,286)%<:mul(945,972)-mul(964,104)mul(163,666)don't()how()select(6,421),what()]mul(94,79)select()~[),-?mul(669,316)what()mul(452,152*~@]~why()how()when()mul(33,805)[how()select()[@]>mul(560,354)from()where()/];,how()} mul(980,896)
->/;%who()&where(504,464);!do(),where()/mul(901,274)!when()mul(76,577)>select()mul(257,297)^+(/what(52,6)@%from()who()mul(331,276),what()when())+]{:&mul(653,163)''; '~mul(946,195)+/[from()mul(345,866)mul(975,684)#-:/why();-<:&mul(416,406)where(864,494)}{mul(343,101where()~ $/!where(331,467)who()@:mul(484,862)' mul(629,780)&%why(436,493)what()$mul(104,102)$,where()mul(2,157)who()%mul(404,895)mul(154,983)):]]%#:do()]from()+/#)/mul(682,696)what(518,168)select()where()+when(518,618)mul(799,756)+}why()}:mul(565,289);mul(687,685);@%!>?why()&;-mul(819,190)}]mul(596,898)mul(113,184)who();<mul(327,372) -when()+mul(327,170)>{@do()@?)why(),*/select()[mul(351,147):{+}}~^from()why()mul(350,104$''(from()<when();$$+mul(992,327);->$!when()<don't()mul(586,163))why()*<:;%mul(698,938)~mul(812,610)))where()^<^^*,<mul(50,331)]),/'}mul(469,997)^&who(){$[!]^#mul(468,630)@(+,}how()mul(97,867)! >mul(574,321)-mul(325,17);<& who(){]mul(960,765)^from())<<$:how()mul(412,562);mul(220,976)<what()how()!~{what()why()@what()mul(350,479)}when()&#@{<mul(135,697)[%mul(419,107)mul(33,102)&mul(430,537)?select(676,300)>*mul(338,463),$/#',from();select()*mul(982,199)&^:&mul(607,915)who()mul(473,531)$mul(490,191)select()&-~+>when()mul(105where()'/!do()<;[#why()mul(453,793)&*,^who()who()what()-<mul(722,985){what()[why()mul(526,659)![mul(863,260)select() /%&what()mul(81,404)}from(947,811)&mul(471,334)//why()}]why()what()~mul(160,947)'mul(679,16)>@why()from(642,548)%^(from()+don't()?-mul(976,65)what();]mul(155,153))where()who()when()[]mul(44,452)}mul(51,177)~$[mul(462,949)$}^select()#[)]mul(743,555)?[mul(750,557)}#{from()%(why()where()mul(807,786)+})>{)what()}do()mul(231 (how()*mul(667,833)when()~from()why(),who()(;)mul(729,999)mul(805,756)when()}what()who()!**who()]mul(920,392*]when(26,462)mul(558,874)!~?+$$';mul(694,195)when()/$?from()mul(198,978)%>;/why()who()}+why()@mul(248,980)$ how()>'select();mul(888,351)mul(476,169)[>@-mul(282,926)#<mul(301,964)mul(736,489)* when()?^mul(306,875) from()%'%from(){+when()<do()~/{#,>why()mul(637,563)-'~@!how()'^what()$mul(303,12)]how()~mul(922,806)^+when()[?don't(){,when()!*select()select(828,14)who()'why()mul(237,256)#mul(603,548)mul(887,362)!>%/how()[,%]don't()&mul(499,574)select()}%how() mul(787,625)what()who()) '*,mul(197,610)?!~('where()%'select()how()mul(840,759)} {$mul(19,916)}/mul(965[)) *who()how()~from()who()how()mul(319,356),where(196,245) who()mulwhat())^when(211,851)select()do()-$>+}'?from();>mul(349,592)&;>mul(473,196)where()~mul(939,515)$?{;^{}what()mul(417,24){??where(390,662)~from(),mul(93,289) when()] #from()$mul(543,426)&;&*;why()where()mulfrom(){-?'>,;who()mul(65,960):!how()*}*mul(544,316)%what(585,366)how():>mul(453,85)&[-from()/-#<select(992,327)mul+$^]from()<%-mul(612,429)/[select(41,104)} }?mul(493,119)$;why();from()%mul(83,70)]><mul}how()-mul(752,957)/%why()#-{mul(148{mul(756,579)who()^-select()-#]mul>?how(829,900)why(){mul(544,588)mul(178,94)from()where()mul(84,939)+^]who()]select()(&mul(951,416)how()[-] mul(324,907)who(){< )mul-select()![@&mul(578,700)mul>@~]%){mul(543,266)#how()!*why()$@%don't()>$what()mul(451,107)/mul(307,230)+@$when()from()?['mul(822,895)$mul(530,529)+mul(792,795]$from()what(158,547){what()mul(851,381)?when(649,20),*mul(73,793)<] :(:@+;mul(93,749)from()];'mul(419,25);:!who()-mul(102,426)'why()+from();mul(186,663)
-select()$how()]%]{~mul(183,456)::,+;![when()>mul(513,696)mul(835,886) from()##* select()%{mul(820,742)don't()why()mul(295,615)^]what()how()-what()*who()why(931,57)when()mul(688,219)!@mul(191,470)how()>?from()from(764,712):where(){:mul(951,673)!mul(536,960)[>?':]mul(353,508)!*how(977,514){)'mul(135,970)}>%select()select()::where()mul(580,976)'^when()mul(144,491)what()%&]mul(360,420(who()how() :@mul(925,707)mul(723,986){mul(465,348) mul(153,323)#~when();from()@}-;<mul(579,823)select())-?$&{select()?~mul(957,611%?from()what()select()('?mul(87,471)#select()-who()@mul(885,437)>] how()[^ :what()mul(990,144)}mul(567,848)select();@where()'(^@mul(620,639))how(711,187)how()select()+?)+^don't()mul(776who()$mul(592,515)what(),who(),mul(870,763)where():when()^>{}mul(503,440)mul(524,563)why(112,173)how()(,[;mul(235,893)how()*?/]mul(524,355%/],^]*^!%where()mul(464,297)select()from()who()};%what();{mul(289,689)#<mul(159,534)-[<why()%~?$<mul(789,482)-{$select()&{how()mul(220,671))!mul(122,701)mul(747,45)#(mul(249,127)~%'#~-where()how(),mul(83,660)}how()!who(882,504)from()where(){{mulwho()<+when(132,720){(from()how()@,mul(829,58):}[mul+)don't()#$?(@'{%where()how()mul(155,396)how()who()];-mul(454,202)mul(733,864))+]<mul(735,897)/ <;mul(80,402))mul(730,574)([*{who(){*who():what()mul(832,674))select()}+(who()-'mul(427,836)mul(79,877)mul(436,288)what(),:,*from()who()how()(mul(327,609)why()!)% &when()mul(372,811)where(623,779)where()@]when()don't()[{from(){mul(842,562)~who()]mul(814,697)']why()mul^*-where()from()>who()[&[mul(604,447)select()~#<;(~^mul(807,738)[~/from()mul(477,588) ^-]-mul(820,399)@-when()>%mul(580,918)mul(401why()*;],why()]]+!mul(343,445)*<+why()$mul(830~@who(),;from()#mul(776,674)?$who(){+mul(295,290){where()when()mul(321,389)&{'@<mul(338,854)*$do()()why()where()select()#where()what()$mul&'$mul(438,58)+mul(176,945)!$<&;do()when())select()}mul]!*why()where()from(),^^(,mul(765,384)@from()why(111,662) }(mul(496,9)*<#mul(391,60)!:;?!]:from()~mul(494,14)(mul(390,353)}@:why()@]where()&mul(885,604)+%@/?mul(254,601)@&:,mul(100,15)%select(301,302)*mul(71,315)(select()mul(871,371)mul(870,594);*#{>$}who()mul(879,995)*{from()/(;)mul(569,107)mul(223,576)~~>*@when()]]mul(624,981)?{^'$>(mul(812,37)what()-?:>&{+mul(731 < %-who()select()mul(656,82)how()'from()mul(446,744)'<;~why()}^:,]mul(461,437)when(245,888);$[%when(896,829)who()>{mul(791,568),-%$! ?#*mul(134,101),~<-'}how(672,596)?/mul(641,129) :from()#?mul(414 )]-%from(),@)(mul(53,315):@& ,who()where(734,227)^mul(271,699)from()>how():when(595,247)'}%',mul(730,933)from()',]why()+ how()where()mul(231,37)~<+&[$:}!:mulwhen(572,105)}who()mul(374,347)/-<mul(660,713)++when()?;who() mul(885,672)>why()*what()how()#!@}mul(230,754)-what()-</# ]don't()~+-:}+why()how()>:mul(759,756)select() $what()]^select()#how()?mul(567,572)mul(757,309)'[mul(530when()+/(*{mul(736,155)+*where(960,885)%: {:mul(713,672)!$*{#%mul(17,60)&what(966,667)what()why(): select(428,568)*!&mul(228,28)where()where()@/])do()}!/{^?{[why()mul(218,633)mul(156,644)>{~^[?mul(377,886) where()%who(159,149));how(909,452)-mul(869,404)>:mul(920,501){what())+how()mul(545,793)~-mul(74,347)where()!-;how()when()>)%mul(647,960})>;&why()mul(767,582)~^+;who()<)<)mul(494,399/;-from()^don't()why()mul(215,704)%%from()mul(392,808)-who()+where()[{-*/mul(869,412)from()mul(501,148)?'don't()from():}select()mul(796,221)select()mul<)@ ,when()}[:[what(96,456)mul(651,592)when()';$ (*why()mul(134,209);select())[mul(434,869)@{+'$mul(292,419)mul(175,874),from()select()$?[{^mul(866,742)
-how()?}% #who()']#!/usr/bin/perlmul(875,840);]:::mul(127,915)>^>select(736,608)why()+ mul(13,855) ++ how()>mul(568,520)<)'mul(332,245)::where()@&mul(625,889):who()^}+<mul(395,46)})mul(123,827){%,~mul(524,599)%-do()#$mul+;when()%where()/]mul(792,461)who()~#~#>who()mul(392,15)*<where(474,62)[-&^mul(908,825),why()what()who(){who()where(168,259)why()&-mul(254,516)[^:who()>how()mul(437,461)select()<who()mul(60,583)/why()+mul(193,822)&,!(}+mul(373,499)mul(574,712)#?)@how()who()mul(365,781)mul(414,354)*#$-;don't()@+from()#(who()mul(798,611)+>]+how()$(!mul(219,47)^mul(518,535)(select()#mul(776,790)who()^how()~)where()mul(622,336)<when(80,174)who()]select()+)]{;mul(506,377)[%(+do()>why(171,784)>];@who()where()mul(255,591) >when()what()-,)&(mul(827,655):)(*mul(457,68)]what()^[&[mul(758</>'/select()mul(351,283)<{&{*%,mul(345,900)&when()<;[:[(when()<mul(752,407){[(,$-select()~<mul[:,]%>select()^(^@mul(904,504){{from()(&mul mul(537,360)why()/{)what())<!how();mul(443,831):&why(),from()%from(644,149)(mul(665,473)$mul(344,505)why()?from()!&don't()!-)'^#*<mul(115,607)~[from():'what()mul(637,79)/@select()when()&#mul(673,108)&&how()}mul(140,971)#select()/mul(659,539)]from()select()select()*mul(855,794)who()/(;&^&/!>mul(812,420){!mul(320,4)who(275,101)who()}^who()^why(562,771)%;-don't()<}}]mul(943,216):{)mul(17,572)&$why()where()mul(86,371)from()$$ +,>,mul(793,733)^?how()&?{;mul(174,855)(#/ what()select()where()[*mul(706,247)who()~why()/#;*mul(459,541) where()}mul(586?(<mul(738,226)(select(){who()mul(395,492)^select()%what():what(661,644)!^^mul(128,575)>mul(470,802)who(773,148){what()?/why(397,830)),where()from()mul(532,611){[select())}why()when()mul(855,381))@?-/) (do()who()mul(4?*select()$select()where()!!mul(758,795)/*:mul(221,874)mul(587,201)~{<mul(495,109)[#{-]? mul(679,206)]}*%-what()*mul(149,552)!mul(422,434))>?{;/why()mul(757,730)'$-$how();do()why(423,32)}%!*from(930,638)mul(196,228)%mul(753,37)<don't()}[>'mul(970,912)[what();~mul(643,558)(where())how()mul(525,660)when()}$#) +mul(770,997)mul(523,352)/%when()mul(139,306)/(mul(435,619)who()$;)mul(353,463)/how()$who()#what()!where()select()mul(218,409)'$:what()^don't()#when()from() &}>where()?mul(41,95)%mul(93,123)mul(615,917)how()^#mul>!@~how()>mul(801,12)select()what(76,678)from()$#}>mul(206,424)/;~select()what()who()%:/mul(920,696)'^+mul(467,893how()mul(732,924)when()*{mul(212,801)select()}$mul(253,942)from(245,298)$%select():mul(200,773)(what() ,mul(484,816%[@:what()$#mul(392,392)select()/mul(603,982){?'}{%mul(767,605)(*select(839,960)from()%,[-{%mul(810,348)<)}:<mul(346,603)%$what()+?$])!mul(915,360)select()who()<!;; )%mul(199,551)&[!'}]*[select()mul(903,980)when()what()what()mul(64)]^-from(),};]mul(492,955)/^%when():what()mul(941,906)/mul(961,134)/when()why()}/,when()mul(21,216)#'when()>$why()-mul(568,717$/*!*:#<<<mul(904,260)why()~why()when()?[{how()$mul(109,697)]when(505,150)--}-why()mul(59,922)?why()&}from(458,92){]mul(177,574)~mul(700,873),#]%#mulwhy()^ mul(671,271)select()&<:~mul(997,472)((mul(995,279)what()(mul(504,172)mul(620,601)!from()<-'@!mul(70,715)&mul(230,991))what())(++mul(448,128)mul(870,892)('mul(583,189)*<why()$!#+;mul(842,739):when()from()why(),what()<select()mul(241,966)from():from(634,254)##-^>mul(519,560)mul(371,303)#mul(444,873)/#;~! mul(825,442)
-;'?,&@@mul(687,295)mul(992,56)from()@$:when();mul(839{*;~mul(577,183)@-where()}why()~@^from()mul(226,901)how() >%when()?[~mul(281,291)#&>#$how()}mul(500,749)&>^-;why()@!@]do()/@?*,</mul(637,962)why())#@]mul(150,192)%(+mulselect()( /mul(455,843)@({-;how()>]~)mul(530,987)who()why()why()@>mul(282,486)*,-from(),>'what()mul(147,97)- /select()<$do()mul(339,632)<+mul(476,603)#$!mul(249,732)![]mul(552,713)}%^:[~who(294,666)!mul(335,971)>#mul(190(how();who():)mul(364,772)[~,when()*mul(350,52;who(640,301)from()-$,%:};mul(407,507))&#why()/{&[mul(668,42),~mul(849,891)what()*-:/who()$mul(335,214)?:why(); ,%mul;}mul(561,325){mul/+?when()<mul(732,879)#+where(){mul(372,617)what()#/mul(307<who()<]where()when()$mul(302,561)where()-mul(165,848):';why()why(){&mul(800,615)how()where()$from()!mul(617,736)select()-~-<!why()$where();mul(505,440)<!;%mul(36,639)!)what() [[}<,mul(100,765)when()(mul(418,224)select();do()-/*!^mul(480,518)>select()#do()!{!)/!mul(398,351)'$]how()%?:)from(716,264)}mul(976,539)when()}/mul(800,619)who())[?mul#+who()}mul(527,856)mul(479,693)[<mul(728,432)*mul(713,727)# }(  where()mul(80,482)what()%,~)-'+/'mul(765,963)[mul&-how()mul(887,370)-{/$'-'where()who()what(295,542)mul(107,111)mulwhere()!where() how()/$mul(461,345)!from()mul(707,778);>what()#}{where()where()mul(310,5)$]>mul(687,149))!how(),why()how(941,130)where()when()what()mul(187,693)(mul(24,418){':}mul(304,601)when()mul(66,524)how()']select()from()>what()mul(217,884)~~<where() ^[mul(304,545)/why(){)[-,what()mul(782,464)why()*when(),}}mul(767,112)*mul(620,773)mul(89,154);]how()<::when()where() mul(430,945)*))mul(143,829)from(192,329)#!{who()($-mul(825,949)do()<%where()![mul(467,327)[&mul(727,778)!*(?@~mul(12,864)from()(; what()where()do()mul(975,968)how()mul(572,737):why()[when()mul(6,295)what()(where()$why()){mul(899,746)-where()mul(28,836)!what()!*,mul(362,377)who(),mul(449,514):?/@%{mul(593,723)^who()(!;[;mul<who()}what()$?from() %:]mul(409,707)<why()why()-;mul(847,518)][select(733,913)@-@why()who()when()mul(159,434)~^^#mul(973,188)-( [,#(%why();mul(970,535)~-,;{mul(776,519)select()'(how()+(mul(341,437)+ >-when())?@/mul(62,572)))when(482,658){select(561,928)when()!({mul(388,507)from()/+mul(64,284)who()why(899,74)when()why()mul(734,117)<-+}#:what()mul(567:where()select()mul(694,323)/[select()mul(772,515)[/'>why()when()/(*@mul(663,798)$when()select()$%#<,select()mul(810,58):~,(+select()[from()~'mul(838,509)mul(227,633)] mul(300,309)]>,!}'mul(369,74)$~({ ~'}{]don't()~)&<[>who()>mul(76,891)!]~ }:)${/mul(226,65)~/&<)^mul(981,600)[who(271,199)~-$&;[where(691,309)mul(69,207)*>select()*(+mul(935,119)<> ?;->mul(116,703)'where()-;;from()mul(324,982from()what()@<^mul(144,872)@+mul(569,48)mul(475,850)*how()'~^-mul(486,714)~![why()why()}<<mul(436,514)mul(574,28)when()'!how()@'what()select()mul(181,123))>/select()how()mul(789,342)?select()]!why()#mul(485,483)'%why();?%who()mul(921,916)do()'*]where()how(977,165)@mul(441,964)*-who()]&mul(395,170)> }mul(583,793)<*-how(){{mul(794,26)<how()&mul(670,885)/? -~<+]mul(32,656)(why()/>%~/;mul(297,812)mul(264,703)[select(253,420)select()mul(665)*when()*/>when()from()/where()!mul(264,312)where(756,949),+{}from()+;mul(454,375),do(){mul(72,780)(who() mul(4,67)
-<mul(299,165) -mul(606,634)&select() /@who()<when()^mul(773,105)when(140,544)mul(565,610)>/why()[*>when())from()mul(163,222)who();select()@?select()mul(631,370)where()^%mul(48,604)%where()select()]%:!{mul(492,16):?select(566,72)'@+when()})mul(277,631)when()when()+%#mul(356,136)mul^who() from()!from()~[don't()?:#!mul(536,636){how()why(167,58){from()$;&mul(170,241)-~$mulwho(),mul(295,979)^when()$}mul(872,817)'^+ %%%@%;mul(796,401){@$from():%(@who()where()do()how();,>where()*how()mul(562,34)mul(851,857)([how()mul(870)do()^#]what()mul(855,774)>from()mul(19,63)<mul(20,808)what()when()}mul(9,565)')#where(){;/mul(485,358){?~#),{when()mul(760,344)where()~#what()from()}}{*'mul(76,561) }why();;what()select()where()#?mul(202,590)#{do();mul(537,456)#^'mul(231,127)when()(%],(}[-^mul(928!how()<when()+(mul(278,743)where()$mul(138#{{mul(612,612){select()@what(186,303),mul(577,261)/{*why()mul(739,172)$@-*;~mul(217,239)(-~;%who()why(681,472)where()who()mul(2%mul(30,720)'what()(~~mul(414,37)/when()%*}}%why()^mul(293,928)%,$#when()/^'don't()&from()mul(662,652why()when()mul(134,920)what()*?!;!how()@from()do()*!~~mul(472,62)])mul:*?@<$when()mul(403,2)mul(682,860):,when(198,260)?:[}[,&mul(940,144<+]*#:mul(274,552)*select()#}<mul(721,529),:who()mul(938,454)[where()mul(816,770)$#^*what()select()'~{select()mul(201,567where(223,485)^]from(721,470)why()>)from()/'#mul(502,592)$(mul(330,265)-*'why()/<@mul(919,336)'when()when()(~ mul(222,37)mul}*?mul(716,440);how(254,405)#mul(938,798)]why()+mul(370,761);+select())mul(308,872):!who()%mul(376,736)when()[don't()$;#~,how():select()~what()mul(793,522)){?who()/mul(812,372)<;@mul(627,289)[!don't()^+)how()$from()##mul(500,501)#{why(120,25)mul(571,651):&{?mul(792,25))@$(@>don't()who()?^')' from()[mul(323,38)when()where()*/who()%mul(623,356)?mul(629,625))}+how()mul(950,826)(+,#mul(947,453)why();'how()&]from()mul(510,94)-?[from(){mul(34,148){when()mul(661,839)do()/}who()mul(597,740)^;mul(6,887)~#how(287,928) $>?}:don't()/-%(,;/))mul(910,743)!:%,%}/mul(626,661)((&([mul(320,446){what()'~<-@mul(573,309)^:?why(352,585)how(328,377)(@mul(996,815),~don't()mul(558,873), what()?>]^$mul(647,533);$@]!when();;when()mul(508,819)&select()( select()mul(823,518)/<when():when()~why(744,571){mul(405,772):;where();(mul(132,162)] !how(74,640):select()/mul(721,482)$why(),*{mul(925,155)*!!<-!}don't(){)+#^mul(990,837)?,mul(867,142)mul(764,232)mul(754,292)$#,select()>-how(),]mul(584,720)mul(127,993)''why()'/![where()mul(130,642)*what()<?where()mul(325,585)select()when()>when()mul(648,223)#}])don't() !when(647,635)+&$ mul(154,204)@~~?mul(90,691)when()]^&from()'[mul(236,768)~where())^~->(&mul(378,233)<?mul(366,515)how()},don't()*what()what()~/select()mul(507,554)<+<mul(145,19)mul(444,536)%!%mul(861,889)#where()}-:{:^ mul(790,870)!from()why(343,804){*-how()from()mul(989,194)<!!when(586,215)where(238,474)}mul(430,90){+who(308,211),mul(365,640)mul(684,600)$^:}from()'&mul(745,81)how()select()who(897,36)]$+?&^don't()what()who(){from()}('$mul(782,153)mul(270,449)#*mul(371,710)]&what()*!{?mul(487,920)(mul(474,993)@%mul(70,96)what()?<@~*mul(639,92)<?%-(mul(154,267));mul(588,135)*$*: [where()mul(2,108)who()/~(why()~/,^mul(364,558)who()#'do()[select()(: when()mul(324,801)where())'#,mul(696,843)*,[mul(946,677)}[how(532,32)[when()'-where(){mul(254,222){select()";
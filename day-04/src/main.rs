@@ -1,81 +1,37 @@
-use std::collections::VecDeque;
-use input;
-use plane;
+use plane::{Grid, Stencil};
 
-// const INPUT_PATH: &str = "./data/day_04_test_input.txt";
-const INPUT_PATH: &str = "./data/day_04_puzzle_input.txt";
+const DAY: u32 = 4;
 
-type LetterGrid = Vec<Vec<char>>;
+const WORD: &str = "XMAS";
+const CROSS: &str = "M.S\n.A.\nM.S";
 
-fn main() {
-    let grid = load_grid();
-    for row in &grid {
-        println!("{row:?}");
-    }
-
-    let count = count_xmas(&grid);
-    println!("{count:?}");
-}
+type LetterGrid = Grid<char>;
 
-fn load_grid() -> LetterGrid {
-    let mut grid: LetterGrid = Vec::new();
-    input::lines(INPUT_PATH, |line| {
-        grid.push(line.chars().collect());
-    });
-    return grid;
-}
-
-fn count_xmas(grid: &LetterGrid) -> i32 {
-    let y_max: usize = grid.len();
-    let mut count = 0;
-    for y in 0..y_max {
-        let x_max: usize = grid[y].len();
-        for x in 0..x_max {
-            let char = grid[y][x];
-            if char == 'A' && is_xmas(plane::Point { x, y }, grid, &plane::Point { x: x_max - 1, y: y_max - 1 }) {
-                count += 1;
-            }
+fn main() {
+    let grid = match input::from_args(DAY).and_then(input::grid) {
+        Ok(grid) => grid,
+        Err(e) => {
+            eprintln!("failed to load grid: {e}");
+            std::process::exit(1);
         }
-    }
-    return count;
-}
+    };
+    print_grid(&grid);
 
-fn is_xmas(at: plane::Point, grid: &LetterGrid, limit: &plane::Point) -> bool {
-    let mut matched_offsets: Vec<plane::Offset> = Vec::new();
-    for y in [-1, 1] {
-        for x in [-1, 1] {
-            let offset = plane::Offset::from(x, y);
-            match offset.apply_within(&at, limit) {
-                None => continue,
-                Some(origin) => {
-                    if is_word(&origin, &offset.inverse(), grid, limit, VecDeque::from(vec!['M', 'A', 'S'])) {
-                        matched_offsets.push(offset);
-                    }
-                }
-            }
-        }
-    }
-    if matched_offsets.len() >= 2 {
-        println!("matched at {at:?} with offsets {matched_offsets:?}");
-        return true;
+    let word: Vec<char> = WORD.chars().collect();
+    let words = plane::find_word(&grid, &word);
+    println!("{WORD} appears {} times", words.len());
+
+    let cross = Stencil::parse(CROSS, '.').expect("cross rows are the same width");
+    let crosses = plane::find_stencil(&grid, &cross);
+    for found in &crosses {
+        println!("matched X-MAS at {} with {:?}", found.origin, found.orientation);
     }
-    return false;
+    println!("X-MAS appears {} times", crosses.len());
 }
 
-fn is_word(origin: &plane::Point, offset: &plane::Offset, grid: &LetterGrid, limit: &plane::Point, mut word: VecDeque<char>) -> bool {
-    match word.pop_front() {
-        None => return true,
-        Some(word_char) => {
-            let grid_char = grid[origin.y][origin.x];
-            if grid_char == word_char {
-                match offset.apply_within(origin, limit) {
-                    None => return word.is_empty(),
-                    Some(point) => {
-                        return is_word(&point, offset, grid, limit, word);
-                    }
-                }
-            }
-        }
+fn print_grid(grid: &LetterGrid) {
+    for row in grid.bounds().rows() {
+        let row: String = row.map(|point| grid[point]).collect();
+        println!("{row}");
     }
-    return false;
 }
@@ -1,26 +1,54 @@
-use std::{collections::HashMap, ops::Div};
-use input;
+use std::{collections::HashMap, ops::Div, str::FromStr};
+use input::InputSource;
 
-// const RULES_PATH: &str = "./data/day_05_test_input_rules.txt";
-// const UPDATES_PATH: &str = "./data/day_05_test_input_updates.txt";
+const DAY: u32 = 5;
 
-const RULES_PATH: &str = "./data/day_05_puzzle_input_rules.txt";
-const UPDATES_PATH: &str = "./data/day_05_puzzle_input_updates.txt";
+type RulesMap = HashMap<i32, Vec<i32>>;
+
+struct Rule {
+    before: i32,
+    after: i32
+}
+
+impl FromStr for Rule {
+    type Err = input::ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (before, after) = input::scan!(s, "{}|{}" => i32, i32)?;
+        Ok(Rule { before, after })
+    }
+}
+
+struct Update {
+    pages: Vec<i32>
+}
+
+impl FromStr for Update {
+    type Err = input::ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pages,) = input::scan!(s, "{}" => Vec<i32>)?;
+        Ok(Update { pages })
+    }
+}
 
 fn main() {
-    let rules = load_rules_map();
+    let (rules, updates) = match input::from_args(DAY).and_then(load_input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("failed to load input: {e}");
+            std::process::exit(1);
+        }
+    };
     // println!("{rules:?}");
 
     let mut correct_updates: Vec<Vec<i32>> = Vec::new();
     let mut incorrect_updates: Vec<Vec<i32>> = Vec::new();
-    input::lines(UPDATES_PATH, |update| {
+    for Update { pages } in updates {
         let mut is_correct_order = true;
 
-        let pages: Vec<i32> = update.split(",")
-            .filter_map(|p| p.parse::<i32>().ok())
-            .collect();
-        for (i, page) in pages.clone().into_iter().enumerate() {
-            match rules.get(&page) {
+        for (i, page) in pages.iter().enumerate() {
+            match rules.get(page) {
                 None => continue,
                 Some(page_rules) => {
                     if !correct_order(&pages[..i], page_rules) {
@@ -37,7 +65,7 @@ fn main() {
         } else {
             incorrect_updates.push(pages);
         }
-    });
+    }
 
     let mut middle_page_sum = 0;
     for correct_update in correct_updates {
@@ -54,34 +82,28 @@ fn main() {
     println!("corrected page sum: {corrected_middle_page_sum}");
 }
 
-fn load_rules_map() -> HashMap<i32, Vec<i32>> {
-    let mut rules: HashMap<i32, Vec<i32>> = HashMap::new();
-    input::lines(RULES_PATH, |line| {
-        match line.split_once("|") {
-            None => panic!("invalid rule: {line}"),
-            Some((x, y)) => {
-                let key = x.parse::<i32>().unwrap();
-                let value = y.parse::<i32>().unwrap();
-                if !rules.contains_key(&key) {
-                    rules.insert(key, Vec::new());
-                }
-                rules.get_mut(&key).unwrap().push(value);
-            }
-        }
-    });
-    return rules;
+fn load_input(source: InputSource) -> Result<(RulesMap, Vec<Update>), input::Error> {
+    let mut sections = input::sections(source)?;
+    let rules = sections.parse_next::<Rule>()?;
+    let updates = sections.parse_next::<Update>()?;
+
+    let mut rules_map: RulesMap = HashMap::new();
+    for rule in rules {
+        rules_map.entry(rule.before).or_default().push(rule.after);
+    }
+    Ok((rules_map, updates))
 }
 
-fn correct_order(pages: &[i32], rules: &Vec<i32>) -> bool {
+fn correct_order(pages: &[i32], rules: &[i32]) -> bool {
     for page in pages {
         if rules.contains(page) {
             return false;
         }
     }
-    return true;
+    true
 }
 
-fn correct_update(pages: &Vec<i32>, rules: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
+fn correct_update(pages: &[i32], rules: &RulesMap) -> Vec<i32> {
     let mut ordered_pages: Vec<i32> = Vec::new();
     for page in pages {
         if ordered_pages.is_empty() {
@@ -106,13 +128,13 @@ fn correct_update(pages: &Vec<i32>, rules: &HashMap<i32, Vec<i32>>) -> Vec<i32>
             }
         }
     }
-    return ordered_pages;
+    ordered_pages
 }
 
-fn get_middle_page(pages: &Vec<i32>) -> i32 {
+fn get_middle_page(pages: &[i32]) -> i32 {
     if pages.len() % 2 == 0 {
         panic!("even update, no middle page!");
     }
     let index = pages.len().div(2);
-    return pages[index];
+    pages[index]
 }
@@ -1,102 +1,66 @@
-use std::{collections::HashSet, hash::Hash};
-use input;
-use plane;
+use input::InputSource;
+use plane::{Outcome, Point, Turn, Walk, Walker};
 
-// const INPUT_PATH: &str = "./data/day_06_test_input.txt";
-const INPUT_PATH: &str = "./data/day_06_puzzle_input.txt";
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Guard {
-    position: plane::Point,
-    direction: plane::Direction
-}
-
-impl Guard {
-    fn next(&self) -> Option<plane::Point> {
-        self.direction.offset().apply(&self.position)
-    }
-
-    fn step(&mut self, to: plane::Point) {
-        self.position = to
-    }
-
-    fn turn(&mut self) {
-        self.direction = self.direction.rotate_cw()
-    }
-}
+const DAY: u32 = 6;
 
 struct Level {
-    limit: plane::Point,
-    obstacles: HashSet<plane::Point>
+    obstacles: plane::Grid<bool>
 }
 
 impl Level {
     fn is_obstacle(&self, point: &plane::Point) -> bool {
-        self.obstacles.contains(point)
+        self.obstacles.get(*point) == Some(&true)
     }
 
-    fn with_obstacle(&self, obstacle: plane::Point) -> Level {
-        let mut obstacles = self.obstacles.clone();
-        obstacles.insert(obstacle);
-
-        Level {
-            limit: self.limit,
-            obstacles
+    /// The guard's move rule: turn right when blocked by an obstacle, or by `extra` if there
+    /// is one, and otherwise step forward. Returns `None` once the guard would leave the level.
+    fn patrol(&self, guard: &Walker, extra: Option<Point>) -> Option<Walker> {
+        let ahead = guard.ahead().filter(|ahead| self.obstacles.bounds().contains(ahead))?;
+        if self.is_obstacle(&ahead) || extra == Some(ahead) {
+            Some(guard.turned(Turn::Right))
+        } else {
+            guard.stepped()
         }
     }
 }
 
 fn main() {
-    match load_level() {
+    match input::from_args(DAY).map_err(|e| e.to_string()).and_then(load_level) {
         Ok((level, guard)) =>  {
             print_level(&level, &guard);
             println!("{guard:?}");
 
-            find_loop_obstacles(&level, &guard);
+            let walk = guard.walk(level.obstacles.bounds(), |guard| level.patrol(guard, None));
+            println!("visited position count: {}", walk.visited_points().count());
+
+            find_loop_obstacles(&level, &guard, &walk);
         },
-        Err(m) => println!("failed to parse level: {m}")
+        Err(m) => {
+            eprintln!("failed to parse level: {m}");
+            std::process::exit(1);
+        }
     }
 }
 
-fn load_level() -> Result<(Level, Guard), String> {
-    let mut guard = Err("guard not found");
-    let mut obstacles: HashSet<plane::Point> = HashSet::new();
-
-    let mut x_limit: usize = 0;
-    let mut y_limit: usize = 0;
+fn load_level(source: InputSource) -> Result<(Level, Walker), String> {
+    let grid = input::grid(source).map_err(|e| e.to_string())?;
 
-    input::lines_indexed(INPUT_PATH, |y, r| {
-        y_limit += 1;
-        x_limit = r.len();
+    let guard = grid.iter()
+        .find(|(_, c)| **c == '^')
+        .map(|(position, _)| Walker::new(position, plane::Direction::Up));
 
-        for (x, c) in r.chars().enumerate() {
-            let point = plane::Point { x, y };
-            if c == '#' {
-                obstacles.insert(point);
-                continue;
-            } else if c == '^' {
-                guard = Ok(Guard {
-                    position: point,
-                    direction: plane::Direction::Up
-                })
-            }
-        }
-    });
-
-    return Ok((
+    Ok((
         Level {
-            limit: plane::Point { x: x_limit, y: y_limit },
-            obstacles
+            obstacles: grid.map(|c| *c == '#')
         },
-        guard?,
-    ));
+        guard.ok_or("guard not found")?,
+    ))
 }
 
-fn print_level(level: &Level, guard: &Guard) {
-    for y in 0..level.limit.y {
-        for x in 0..level.limit.x {
-            let point = plane::Point { x, y };
-            if level.obstacles.contains(&point) {
+fn print_level(level: &Level, guard: &Walker) {
+    for row in level.obstacles.bounds().rows() {
+        for point in row {
+            if level.is_obstacle(&point) {
                 print!("#");
             } else if guard.position == point {
                 print!("^");
@@ -109,66 +73,13 @@ fn print_level(level: &Level, guard: &Guard) {
     println!();
 }
 
-// fn debug_print_level(level: &Level, guard: &Guard, visited: &HashSet<Guard>, test_obstacle: &plane::Point) {
-//     let visited_points: HashSet<plane::Point> = HashSet::from_iter(visited.iter().map(|g| g.position));
-//     for y in 0..level.limit.y {
-//         for x in 0..level.limit.x {
-//             let point = plane::Point { x, y };
-//             if point == *test_obstacle {
-//                 print!("0");
-//             } else if visited_points.contains(&point) {
-//                 print!("X")
-//             } else if level.obstacles.contains(&point) {
-//                 print!("#");
-//             } else if guard.position == point {
-//                 print!("^");
-//             } else {
-//                 print!(".");
-//             }
-//         }
-//         println!();
-//     }
-//     println!();
-// }
-
-fn find_loop_obstacles(level: &Level, guard: &Guard) {
-    let mut loop_obstacles = HashSet::new();
-    walk(*guard, level, |ghost| {
-        let to = ghost.next().unwrap();
-        let new_level = level.with_obstacle(to);
-
-        let mut visited = HashSet::new();
-        walk(*guard, &new_level, |g: Guard| {
-            if visited.contains(&g) {
-                loop_obstacles.insert(to);
-                return false;
-            } else {
-                visited.insert(g);
-                return true;
-            }
-        });
-        return true;
-    });
-    println!("loop obstacle count: {}", loop_obstacles.len());
-}
-
-fn walk<F>(mut guard: Guard, level: &Level, mut cont: F) where F: FnMut(Guard) -> bool {
-    match guard.next() {
-        None => (),
-        Some(to) => {
-            if to.x >= level.limit.x || to.y >= level.limit.y {
-                return;
-            }
-
-            if level.is_obstacle(&to) {
-                guard.turn();
-            } else {
-                if !cont(guard) {
-                    return;
-                }
-                guard.step(to);
-            }
-            return walk(guard, level, cont);
-        }
-    }
+fn find_loop_obstacles(level: &Level, guard: &Walker, walk: &Walk) {
+    let count = walk.visited_points()
+        .filter(|point| *point != guard.position)
+        .filter(|point| {
+            let simulation = plane::simulate(*guard, |guard| level.patrol(guard, Some(*point)));
+            matches!(simulation.outcome, Outcome::Looped(_))
+        })
+        .count();
+    println!("loop obstacle count: {count}");
 }
@@ -1,8 +1,7 @@
 use core::fmt;
-use std::collections::VecDeque;
+use std::{collections::VecDeque, str::FromStr};
 
-// const INPUT_PATH: &str = "./data/day_07_test_input.txt";
-const INPUT_PATH: &str = "./data/day_07_puzzle_input.txt";
+const DAY: u32 = 7;
 
 #[derive(Debug)]
 struct Equation {
@@ -10,6 +9,15 @@ struct Equation {
     values: Vec<u64>
 }
 
+impl FromStr for Equation {
+    type Err = input::ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (result, values) = input::scan!(s, "{}: {}" => u64, Vec<u64>)?;
+        Ok(Equation { result, values })
+    }
+}
+
 enum Operator {
     Add,
     Multiply,
@@ -41,7 +49,13 @@ impl Operator {
 }
 
 fn main() {
-    let equations = load_equations();
+    let equations = match input::from_args(DAY).and_then(input::parse_lines::<Equation>) {
+        Ok(equations) => equations,
+        Err(e) => {
+            eprintln!("failed to load equations: {e}");
+            std::process::exit(1);
+        }
+    };
 
     let mut total: u64 = 0;
     for equation in equations {
@@ -52,19 +66,6 @@ fn main() {
     println!("total calibration result: {total}");
 }
 
-fn load_equations() -> Vec<Equation> {
-    let mut equations = Vec::new();
-    input::lines(INPUT_PATH, |line| {
-        let (result, values) = line.split_once(":").unwrap();
-        let equation = Equation {
-            result: result.parse::<u64>().unwrap(),
-            values: values.split_whitespace().map(|v| v.parse::<u64>().unwrap()).collect()
-        };
-        equations.push(equation);
-    });
-    return equations;
-}
-
 fn is_true(equation: &Equation) -> bool {
     match equation.values.split_first() {
         None => false,
@@ -80,7 +81,7 @@ fn is_true(equation: &Equation) -> bool {
                         }
                     }
                     println!(" = {}", equation.result);
-                    return true;
+                    true
                 }
             }
         }
@@ -106,7 +107,7 @@ fn evaluate(target_result: u64, running_result: u64, remaining_values: &[u64]) -
                     }
                 }
             }
-            return None;
+            None
         }
     }
 }
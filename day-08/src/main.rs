@@ -1,13 +1,18 @@
 use std::collections::{HashMap, HashSet};
 
-use input;
+use input::InputSource;
 use plane::*;
 
-// const INPUT_PATH: &str = "./data/day_08_test_input.txt";
-const INPUT_PATH: &str = "./data/day_08_puzzle_input.txt";
+const DAY: u32 = 8;
 
 fn main() {
-    let (limit, frequencies) = load_frequencies();
+    let (limit, frequencies) = match input::from_args(DAY).and_then(load_frequencies) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("failed to load frequencies: {e}");
+            std::process::exit(1);
+        }
+    };
     // println!("{frequencies:?}");
 
     let antinodes = find_antinodes(&limit, &frequencies);
@@ -18,32 +23,23 @@ fn main() {
     println!("unique antinodes: {}", antinodes.len());
 }
 
-fn load_frequencies() -> (Rect, HashMap<char, Vec<Point>>) {
-    let mut width: usize = 0;
-    let mut height: usize = 0;
+fn load_frequencies(source: InputSource) -> Result<(Rect, HashMap<char, Vec<Point>>), input::Error> {
+    let grid = input::grid(source)?;
 
     let mut frequencies = HashMap::new();
-    input::lines_indexed(INPUT_PATH, |y, line| {
-        width = line.len();
-        height = y + 1;
-
-        for (x, frequency) in line.chars().enumerate() {
-            if frequency.is_alphanumeric() {
-                frequencies.entry(frequency)
-                    .or_insert(Vec::new())
-                    .push(Point { x, y });
-            }
+    for (point, frequency) in grid.iter() {
+        if frequency.is_alphanumeric() {
+            frequencies.entry(*frequency)
+                .or_insert(Vec::new())
+                .push(point);
         }
-    });
-    return (
-        Rect { width, height },
-        frequencies
-    );
+    }
+    Ok((grid.bounds(), frequencies))
 }
 
 fn find_antinodes(limit: &Rect, frequencies: &HashMap<char, Vec<Point>>) -> HashSet<Point> {
     let mut antinodes: HashSet<Point> = HashSet::new();
-    for (_, antennas) in frequencies {
+    for antennas in frequencies.values() {
         for t in 0..antennas.len() {
             let target = antennas.get(t).unwrap();
             if !antennas.is_empty() {
@@ -53,31 +49,17 @@ fn find_antinodes(limit: &Rect, frequencies: &HashMap<char, Vec<Point>>) -> Hash
                 let subject = antennas.get(s).unwrap();
                 antinodes.insert(*subject);
 
-                let offset = target.offset_from(subject);
-                antinodes.extend(find_antinodes_from(target, limit, &offset));
-                antinodes.extend(find_antinodes_from(subject, limit, &offset.inverse()));
+                let step = (*target - *subject).reduced();
+                antinodes.extend(find_antinodes_from(target, limit, &step));
+                antinodes.extend(find_antinodes_from(target, limit, &-step));
             }
         }
     }
-    return antinodes;
+    antinodes
 }
 
 fn find_antinodes_from(from: &Point, limit: &Rect, offset: &Offset) -> HashSet<Point> {
-    let mut antinodes: HashSet<Point> = HashSet::new();
-    let mut next = *from;
-    loop {
-        match offset.apply(&next) {
-            Some(antinode) => {
-                if !limit.contains(&antinode) {
-                    break;
-                }
-                antinodes.insert(antinode);
-                next = antinode;
-            }
-            None => break
-        }
-    }
-    return antinodes;
+    Ray::new(*from, *offset).within(*limit).collect()
 }
 
 fn print_map(limit: &Rect, frequencies: &HashMap<char, Vec<Point>>, antinodes: &HashSet<Point>) {
@@ -88,13 +70,11 @@ fn print_map(limit: &Rect, frequencies: &HashMap<char, Vec<Point>>, antinodes: &
         }
     }
 
-    for y in 0..limit.height {
-        for x in 0..limit.width {
-            let point = Point { x, y };
+    for row in limit.rows() {
+        for point in row {
             if antenna_frequencies.contains_key(&point) {
                 print!("{}", antenna_frequencies[&point]);
-            } else
-            if antinodes.contains(&point) {
+            } else if antinodes.contains(&point) {
                 print!("#");
             } else {
                 print!(".");
@@ -0,0 +1,65 @@
+use std::{env, path::{Path, PathBuf}};
+
+use crate::{Error, InputSource};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Puzzle
+}
+
+impl InputKind {
+    fn file_suffix(&self) -> &'static str {
+        match self {
+            InputKind::Example => "test",
+            InputKind::Puzzle => "puzzle"
+        }
+    }
+}
+
+/// The workspace's `data/` directory, independent of the current directory.
+pub fn data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("data")
+}
+
+/// The conventional `data/day_NN_{test,puzzle}_input.txt` path for a day.
+pub fn data_path(day: u32, kind: InputKind) -> PathBuf {
+    data_dir().join(format!("day_{day:02}_{}_input.txt", kind.file_suffix()))
+}
+
+/// Resolves the input for `day` from the process arguments, see [`parse_args`].
+pub fn from_args(day: u32) -> Result<InputSource, Error> {
+    parse_args(day, env::args().skip(1))
+}
+
+/// Resolves `--example`, `--puzzle` (the default) or `--input <path>` to an input source.
+/// A path of `-` reads from stdin.
+pub fn parse_args<I>(day: u32, args: I) -> Result<InputSource, Error>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>
+{
+    let mut source = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let selected = match arg.as_ref() {
+            "--example" => InputSource::File(data_path(day, InputKind::Example)),
+            "--puzzle" => InputSource::File(data_path(day, InputKind::Puzzle)),
+            "--input" => match args.next() {
+                Some(path) if path.as_ref() == "-" => InputSource::Stdin,
+                Some(path) => InputSource::from(path.as_ref()),
+                None => return Err(Error::Args("--input requires a path".to_string()))
+            },
+            other => return Err(Error::Args(format!("unexpected argument {other:?}, expected --example, --puzzle or --input <path>")))
+        };
+
+        if source.is_some() {
+            return Err(Error::Args("only one of --example, --puzzle or --input may be given".to_string()));
+        }
+        source = Some(selected);
+    }
+    Ok(source.unwrap_or_else(|| InputSource::File(data_path(day, InputKind::Puzzle))))
+}
@@ -0,0 +1,82 @@
+use core::fmt;
+use std::{error, io};
+
+pub type BoxError = Box<dyn error::Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error
+    },
+    Parse {
+        path: String,
+        line: usize,
+        text: String,
+        source: BoxError
+    },
+    /// A single character of a grid failed to map to a cell.
+    Cell {
+        path: String,
+        line: usize,
+        column: usize,
+        text: String,
+        source: BoxError
+    },
+    RaggedRow {
+        path: String,
+        line: usize,
+        expected: usize,
+        found: usize
+    },
+    MissingSection {
+        path: String,
+        section: usize
+    },
+    Args(String),
+    Fetch {
+        url: String,
+        message: String
+    },
+    MissingSession
+}
+
+impl Error {
+    pub(crate) fn io(path: &str, source: io::Error) -> Error {
+        Error::Io { path: path.to_string(), source }
+    }
+
+    pub(crate) fn parse<E: Into<BoxError>>(path: &str, line: usize, text: &str, source: E) -> Error {
+        Error::Parse {
+            path: path.to_string(),
+            line,
+            text: text.to_string(),
+            source: source.into()
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{path}: {source}"),
+            Error::Parse { path, line, text, source } => write!(f, "{path}:{line}: {source} (in {text:?})"),
+            Error::Cell { path, line, column, text, source } => write!(f, "{path}:{line}:{column}: {source} (in {text:?})"),
+            Error::RaggedRow { path, line, expected, found } => write!(f, "{path}:{line}: expected a row of width {expected}, found {found}"),
+            Error::MissingSection { path, section } => write!(f, "{path}: missing section {section}"),
+            Error::Args(message) => write!(f, "invalid arguments: {message}"),
+            Error::Fetch { url, message } => write!(f, "failed to fetch {url}: {message}"),
+            Error::MissingSession => write!(f, "no session token, set AOC_SESSION or AOC_SESSION_FILE")
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } | Error::Cell { source, .. } => Some(source.as_ref()),
+            Error::RaggedRow { .. } | Error::MissingSection { .. } | Error::Args(_) | Error::Fetch { .. } | Error::MissingSession => None
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::{env, fs, io, path::{Path, PathBuf}};
+
+use crate::{args, Error, InputKind, InputSource};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the base URL, e.g. to point at a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Path of a file holding the session token, defaults to `.session` in the workspace root.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+
+/// Downloads puzzle inputs into the data directory, reusing the cached copy once one exists.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    data_dir: PathBuf
+}
+
+impl Default for Fetcher {
+    fn default() -> Self {
+        Fetcher::new()
+    }
+}
+
+impl Fetcher {
+    pub fn new() -> Fetcher {
+        let fetcher = Fetcher {
+            base_url: String::new(),
+            session: None,
+            data_dir: args::data_dir()
+        };
+        fetcher.base_url(&env_var(BASE_URL_VAR).unwrap_or(DEFAULT_BASE_URL.to_string()))
+    }
+
+    pub fn base_url(mut self, base_url: &str) -> Fetcher {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn session(mut self, session: &str) -> Fetcher {
+        self.session = Some(session.to_string());
+        self
+    }
+
+    pub fn data_dir<P: AsRef<Path>>(mut self, data_dir: P) -> Fetcher {
+        self.data_dir = data_dir.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        let file_name = args::data_path(day, InputKind::Puzzle);
+        self.data_dir.join(file_name.file_name().unwrap())
+    }
+
+    /// Returns the cached input for `day`, downloading it first if it isn't cached yet.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<PathBuf, Error> {
+        let path = self.cache_path(day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let session = self.resolve_session()?;
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", "github.com/SpencerHaan/adventofcode2024 input fetcher")
+            .call()
+            .map_err(|e| Error::Fetch { url: url.clone(), message: e.to_string() })?;
+        let data = response.into_string()
+            .map_err(|e| Error::Fetch { url: url.clone(), message: e.to_string() })?;
+
+        write_atomic(&path, &data).map_err(|e| Error::io(&path.display().to_string(), e))?;
+        Ok(path)
+    }
+
+    fn resolve_session(&self) -> Result<String, Error> {
+        if let Some(session) = &self.session {
+            return Ok(session.clone());
+        }
+        if let Some(session) = env_var(SESSION_VAR) {
+            return Ok(session);
+        }
+
+        let session_file = env_var(SESSION_FILE_VAR)
+            .map(PathBuf::from)
+            .unwrap_or(args::data_dir().with_file_name(".session"));
+        match fs::read_to_string(&session_file) {
+            Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+            _ => Err(Error::MissingSession)
+        }
+    }
+}
+
+/// Fetches (or reuses the cached) puzzle input for `day` using the default [`Fetcher`].
+pub fn fetch(year: u32, day: u32) -> Result<InputSource, Error> {
+    Fetcher::new().fetch(year, day).map(InputSource::File)
+}
+
+/// The trimmed value of `name`, treating an empty value the same as an unset one.
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn write_atomic(path: &Path, data: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, data)?;
+    fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, ErrorKind, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    /// Answers a single request with `body`, returning the request line and `Cookie` header.
+    fn serve_once(listener: TcpListener, body: &'static str) -> thread::JoinHandle<(TcpListener, String, String)> {
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut cookie = String::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("cookie") {
+                        cookie = value.trim().to_string();
+                    }
+                }
+            }
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            (listener, request_line.trim().to_string(), cookie)
+        })
+    }
+
+    #[test]
+    fn fetches_once_then_reads_the_cache() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let data_dir = env::temp_dir().join(format!("input-fetch-test-{}", std::process::id()));
+        let fetcher = Fetcher::new()
+            .base_url(&base_url)
+            .session("secret")
+            .data_dir(&data_dir);
+
+        let server = serve_once(listener, "1 2 3\n");
+        let path = fetcher.fetch(2024, 7).unwrap();
+        let (listener, request_line, cookie) = server.join().unwrap();
+        assert_eq!(request_line, "GET /2024/day/7/input HTTP/1.1");
+        assert_eq!(cookie, "session=secret");
+        assert_eq!(path, fetcher.cache_path(7));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        assert_eq!(fetcher.fetch(2024, 7).unwrap(), path);
+        listener.set_nonblocking(true).unwrap();
+        assert_eq!(listener.accept().unwrap_err().kind(), ErrorKind::WouldBlock);
+
+        fs::remove_dir_all(&data_dir).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+use std::convert::Infallible;
+
+use plane::{Grid, Rect};
+
+use crate::{BoxError, Error, InputSource, Lines};
+
+/// Loads a grid with one `char` per cell.
+pub fn grid(source: impl Into<InputSource>) -> Result<Grid<char>, Error> {
+    grid_with(source, Ok::<char, Infallible>)
+}
+
+/// Loads a grid, mapping every character to a cell with `f`.
+///
+/// Leading and trailing blank lines are skipped, every other row must be as wide as the first.
+pub fn grid_with<T, E, F>(source: impl Into<InputSource>, mut f: F) -> Result<Grid<T>, Error>
+where
+    F: FnMut(char) -> Result<T, E>,
+    E: Into<BoxError>
+{
+    let lines = Lines::open(source)?;
+    let path = lines.path().to_string();
+
+    let mut width: Option<usize> = None;
+    let mut height: usize = 0;
+    let mut blank_line: Option<usize> = None;
+    let mut cells = Vec::new();
+    for line in lines {
+        let (line_no, line) = line?;
+        if line.is_empty() {
+            if width.is_some() {
+                blank_line = blank_line.or(Some(line_no));
+            }
+            continue;
+        }
+
+        let row_width = line.chars().count();
+        match (width, blank_line) {
+            (None, _) => width = Some(row_width),
+            (Some(expected), Some(blank_line)) => {
+                return Err(Error::RaggedRow { path, line: blank_line, expected, found: 0 });
+            },
+            (Some(expected), None) if expected != row_width => {
+                return Err(Error::RaggedRow { path, line: line_no, expected, found: row_width });
+            },
+            (Some(_), None) => ()
+        }
+
+        for (x, c) in line.chars().enumerate() {
+            let cell = f(c).map_err(|e| Error::Cell {
+                path: path.clone(),
+                line: line_no,
+                column: x + 1,
+                text: line.clone(),
+                source: e.into()
+            })?;
+            cells.push(cell);
+        }
+        height += 1;
+    }
+
+    let bounds = Rect::new(width.unwrap_or(0), height);
+    Ok(Grid::new(bounds, cells).expect("one cell per point"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn skips_leading_and_trailing_blank_lines() {
+        let trailing = grid(InputSource::Text("ab\ncd\n\n\n")).unwrap();
+        assert_eq!(trailing.bounds(), Rect::new(2, 2));
+        let padded = grid(InputSource::Text("\n\nab\ncd\n\n")).unwrap();
+        assert_eq!(padded.bounds(), Rect::new(2, 2));
+        assert_eq!(padded[plane::Point { x: 0, y: 0 }], 'a');
+    }
+
+    #[test]
+    fn rejects_interior_blank_lines() {
+        let error = grid(InputSource::Text("ab\n\ncd\n")).unwrap_err();
+        assert!(matches!(error, Error::RaggedRow { line: 2, expected: 2, found: 0, .. }));
+        let error = grid(InputSource::Text("\nab\n\n\ncd\n")).unwrap_err();
+        assert!(matches!(error, Error::RaggedRow { line: 3, expected: 2, found: 0, .. }));
+    }
+
+    #[test]
+    fn keeps_the_mapping_error() {
+        let error = grid_with(InputSource::Text("12\n3x\n"), |c| c.to_string().parse::<u32>()).unwrap_err();
+        assert!(matches!(error, Error::Cell { line: 2, column: 2, .. }));
+        assert!(error.source().unwrap().downcast_ref::<std::num::ParseIntError>().is_some());
+    }
+}
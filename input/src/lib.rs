@@ -1,23 +1,76 @@
-use std::fs;
-
-pub fn lines<F>(path: &str, mut f: F) where F: FnMut(&str) {
-    match fs::read_to_string(path) {
-        Ok(data) => {
-            for line in data.split_terminator("\n") {
-                f(line);
-            }
-        },
+use std::{io::BufRead, str::FromStr};
+
+mod args;
+mod error;
+#[cfg(feature = "fetch")]
+mod fetch;
+mod grid;
+mod lines;
+mod numbers;
+mod scan;
+mod sections;
+mod source;
+
+pub use args::{data_dir, data_path, from_args, parse_args, InputKind};
+pub use error::{BoxError, Error};
+#[cfg(feature = "fetch")]
+pub use fetch::{fetch, Fetcher};
+pub use grid::{grid, grid_with};
+pub use lines::Lines;
+pub use numbers::{digits, numbers, Integer, NumberError, Numbers};
+pub use scan::{Captures, Pattern, Scan, ScanError};
+pub use sections::{Section, Sections};
+pub use source::InputSource;
+
+/// Calls `f` with every line of `source`, panicking on any I/O error.
+#[deprecated(note = "use `parse_lines` or `Lines`, which report errors instead of panicking")]
+pub fn lines<S, F>(source: S, mut f: F) where S: Into<InputSource>, F: FnMut(&str) {
+    #[allow(deprecated)]
+    lines_indexed(source, |_, line| f(line));
+}
+
+/// Calls `f` with the zero-based index and text of every line of `source`, panicking on any
+/// I/O error.
+#[deprecated(note = "use `Lines`, which reports errors instead of panicking")]
+pub fn lines_indexed<S, F>(source: S, mut f: F) where S: Into<InputSource>, F: FnMut(usize, &str) {
+    let lines = match Lines::open(source) {
+        Ok(lines) => lines,
         Err(e) => panic!("{e:?}")
+    };
+    for line in lines {
+        match line {
+            Ok((line_no, line)) => f(line_no - 1, &line),
+            Err(e) => panic!("{e:?}")
+        }
     }
 }
 
-pub fn lines_indexed<F>(path: &str, mut f: F) where F: FnMut(usize, &str) {
-    match fs::read_to_string(path) {
-        Ok(data) => {
-            for (i, line) in data.split_terminator("\n").enumerate() {
-                f(i, line);
-            }
-        },
-        Err(e) => panic!("{e:?}")
+pub fn read_to_string<S: Into<InputSource>>(source: S) -> Result<String, Error> {
+    source.into().read_to_string()
+}
+
+/// Parses every non-empty line of `source` as a `T`, reporting the first line that fails.
+pub fn parse_lines<T>(source: impl Into<InputSource>) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>
+{
+    let lines = Lines::open(source)?;
+    let path = lines.path().to_string();
+
+    let mut values = Vec::new();
+    for line in lines {
+        let (line_no, line) = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let value = line.parse::<T>().map_err(|e| Error::parse(&path, line_no, &line, e))?;
+        values.push(value);
     }
+    Ok(values)
+}
+
+/// Splits `source` into its blank-line separated sections.
+pub fn sections<S: Into<InputSource>>(source: S) -> Result<Sections<Box<dyn BufRead>>, Error> {
+    Sections::open(source)
 }
@@ -0,0 +1,92 @@
+use std::{io::BufRead, iter::FusedIterator};
+
+use crate::{Error, InputSource};
+
+/// Streams the lines of a reader as `(line_no, line)` pairs, numbering lines from 1.
+///
+/// Ends after the first I/O error.
+pub struct Lines<R> {
+    path: String,
+    reader: R,
+    line_no: usize,
+    done: bool
+}
+
+impl Lines<Box<dyn BufRead>> {
+    pub fn open<S: Into<InputSource>>(source: S) -> Result<Self, Error> {
+        let source = source.into();
+        Ok(Lines::new(&source.name(), source.reader()?))
+    }
+}
+
+impl<R: BufRead> Lines<R> {
+    /// `path` is only used to label errors, so any name describing the reader will do.
+    pub fn new(path: &str, reader: R) -> Self {
+        Lines {
+            path: path.to_string(),
+            reader,
+            line_no: 0,
+            done: false
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<(usize, String), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => {
+                self.done = true;
+                None
+            },
+            Ok(_) => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                self.line_no += 1;
+                Some(Ok((self.line_no, line)))
+            },
+            Err(e) => {
+                self.done = true;
+                Some(Err(Error::io(&self.path, e)))
+            }
+        }
+    }
+}
+
+impl<R: BufRead> FusedIterator for Lines<R> {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::*;
+
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("broken"))
+        }
+    }
+
+    #[test]
+    fn lines_end_after_an_error() {
+        let mut lines = Lines::new("broken", BufReader::new(Broken));
+        assert!(matches!(lines.next(), Some(Err(Error::Io { .. }))));
+        assert!(lines.next().is_none());
+        assert_eq!(Lines::new("broken", BufReader::new(Broken)).flatten().count(), 0);
+    }
+}
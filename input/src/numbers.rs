@@ -0,0 +1,126 @@
+use core::fmt;
+use std::{any, error, marker::PhantomData, num::ParseIntError};
+
+/// Primitive integers that [`numbers`] can extract.
+pub trait Integer: Sized {
+    const SIGNED: bool;
+
+    fn parse(text: &str) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! integer {
+    ($signed:expr => $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+
+                fn parse(text: &str) -> Result<Self, ParseIntError> {
+                    text.parse::<$t>()
+                }
+            }
+        )*
+    };
+}
+
+integer!(false => u8, u16, u32, u64, u128, usize);
+integer!(true => i8, i16, i32, i64, i128, isize);
+
+/// Yields every integer in `line`, ignoring whatever separates them.
+///
+/// A `-` directly before a digit is a sign for signed types and a separator otherwise.
+pub fn numbers<T: Integer>(line: &str) -> Numbers<'_, T> {
+    Numbers {
+        rest: line,
+        number: PhantomData
+    }
+}
+
+/// Yields the value of every decimal digit in `line`, skipping other characters.
+pub fn digits(line: &str) -> impl Iterator<Item = u32> + '_ {
+    line.chars().filter_map(|c| c.to_digit(10))
+}
+
+pub struct Numbers<'a, T> {
+    rest: &'a str,
+    number: PhantomData<T>
+}
+
+impl<T: Integer> Iterator for Numbers<'_, T> {
+    type Item = Result<T, NumberError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.rest.as_bytes();
+        let digit = bytes.iter().position(|b| b.is_ascii_digit())?;
+        let start = if T::SIGNED && digit > 0 && bytes[digit - 1] == b'-' { digit - 1 } else { digit };
+        let end = bytes[digit..].iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |len| digit + len);
+
+        let text = &self.rest[start..end];
+        self.rest = &self.rest[end..];
+        Some(T::parse(text).map_err(|source| NumberError {
+            text: text.to_string(),
+            type_name: any::type_name::<T>(),
+            source
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberError {
+    text: String,
+    type_name: &'static str,
+    source: ParseIntError
+}
+
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in {}: {}", self.text, self.type_name, self.source)
+    }
+}
+
+impl error::Error for NumberError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<T: Integer>(line: &str) -> Result<Vec<T>, NumberError> {
+        numbers(line).collect()
+    }
+
+    #[test]
+    fn signs_on_signed_types() {
+        assert_eq!(all::<i32>("p=-3,14 v=2,-7"), Ok(vec![-3, 14, 2, -7]));
+        assert_eq!(all::<i64>("7-2"), Ok(vec![7, -2]));
+        assert_eq!(all::<i32>("--5 - 6"), Ok(vec![-5, 6]));
+    }
+
+    #[test]
+    fn dashes_separate_unsigned_types() {
+        assert_eq!(all::<u32>("-5"), Ok(vec![5]));
+        assert_eq!(all::<u32>("7-2"), Ok(vec![7, 2]));
+        assert_eq!(all::<usize>("no numbers here"), Ok(vec![]));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let error = all::<u8>("12 300 4").unwrap_err();
+        assert_eq!(error.to_string(), "300 does not fit in u8: number too large to fit in target type");
+        let error = all::<i8>("-129").unwrap_err();
+        assert_eq!(error.to_string(), "-129 does not fit in i8: number too small to fit in target type");
+        let mut found = numbers::<u8>("1 256 3");
+        assert_eq!(found.next(), Some(Ok(1)));
+        assert!(found.next().unwrap().is_err());
+        assert_eq!(found.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn digits_skip_everything_else() {
+        assert_eq!(digits("2a3-3 1").collect::<Vec<_>>(), [2, 3, 3, 1]);
+    }
+}
@@ -0,0 +1,333 @@
+use core::fmt;
+use std::{error, vec};
+
+use crate::BoxError;
+
+/// A line template made of literal text and `{}` captures, e.g. `"{}: {}"`.
+///
+/// Whitespace in the template matches any non-empty run of whitespace, and `{{`/`}}`
+/// stand for literal braces. Each capture takes the text up to the next literal, so the
+/// last one runs to the end of the line. Leading whitespace is skipped when the template
+/// starts with a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    template: String,
+    segments: Vec<Segment>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Capture
+}
+
+impl Pattern {
+    pub fn new(template: &str) -> Result<Pattern, ScanError> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    literal.push(c);
+                },
+                ('{', Some('}')) => {
+                    chars.next();
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(literal.split_off(0)));
+                    }
+                    if segments.last() == Some(&Segment::Capture) {
+                        return Err(ScanError::template(template, "adjacent captures need a literal between them"));
+                    }
+                    segments.push(Segment::Capture);
+                },
+                ('{', _) | ('}', _) => return Err(ScanError::template(template, "unmatched brace, use {{ or }} for a literal brace")),
+                _ => literal.push(c)
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Pattern {
+            template: template.to_string(),
+            segments
+        })
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    pub fn capture_count(&self) -> usize {
+        self.segments.iter().filter(|s| **s == Segment::Capture).count()
+    }
+
+    /// Splits `text` into the raw text of each capture.
+    pub fn captures<'t>(&'t self, text: &'t str) -> Result<Captures<'t>, ScanError> {
+        let mut captures = Vec::new();
+        let mut rest = match self.segments.first() {
+            Some(Segment::Capture) => text.trim_start_matches(is_space),
+            _ => text
+        };
+        let mut segments = self.segments.iter().peekable();
+        while let Some(segment) = segments.next() {
+            let column = text.len() - rest.len() + 1;
+            match segment {
+                Segment::Literal(literal) => match match_literal(literal, rest) {
+                    Some(len) => rest = &rest[len..],
+                    None => return Err(self.error(text, format!("expected {literal:?} at column {column}")))
+                },
+                Segment::Capture => match segments.peek() {
+                    Some(Segment::Literal(literal)) => match find_literal(literal, rest) {
+                        Some(start) => {
+                            captures.push(&rest[..start]);
+                            rest = &rest[start..];
+                        },
+                        None => return Err(self.error(text, format!("expected {literal:?} after column {column}")))
+                    },
+                    _ => {
+                        captures.push(rest);
+                        rest = "";
+                    }
+                }
+            }
+        }
+        if !rest.is_empty() {
+            let column = text.len() - rest.len() + 1;
+            return Err(self.error(text, format!("unexpected {rest:?} at column {column}")));
+        }
+
+        Ok(Captures {
+            pattern: self,
+            text,
+            captures: captures.into_iter(),
+            index: 0
+        })
+    }
+
+    fn error(&self, text: &str, message: String) -> ScanError {
+        ScanError {
+            template: self.template.clone(),
+            text: text.to_string(),
+            message
+        }
+    }
+}
+
+/// The raw captures of one match, decoded in order with [`Captures::decode`].
+pub struct Captures<'t> {
+    pattern: &'t Pattern,
+    text: &'t str,
+    captures: vec::IntoIter<&'t str>,
+    index: usize
+}
+
+impl<'t> Captures<'t> {
+    /// Decodes the next capture as a `T`.
+    pub fn decode<T: Scan>(&mut self) -> Result<T, ScanError> {
+        self.index += 1;
+        match self.captures.next() {
+            Some(capture) => T::scan(capture).map_err(|e| {
+                self.pattern.error(self.text, format!("capture {} ({capture:?}): {e}", self.index))
+            }),
+            None => Err(self.pattern.error(self.text, format!("template has no capture {}", self.index)))
+        }
+    }
+
+    /// Fails if any captures were left undecoded.
+    pub fn finish(self) -> Result<(), ScanError> {
+        match self.captures.len() {
+            0 => Ok(()),
+            remaining => Err(self.pattern.error(self.text, format!("{remaining} captures left undecoded")))
+        }
+    }
+}
+
+fn is_space(c: char) -> bool {
+    c.is_whitespace()
+}
+
+/// The length of the prefix of `text` matching `literal`, if any.
+fn match_literal(literal: &str, text: &str) -> Option<usize> {
+    let mut rest = text;
+    let mut literal = literal;
+    while let Some(c) = literal.chars().next() {
+        if is_space(c) {
+            literal = literal.trim_start_matches(is_space);
+            let trimmed = rest.trim_start_matches(is_space);
+            if trimmed.len() == rest.len() {
+                return None;
+            }
+            rest = trimmed;
+        } else {
+            rest = rest.strip_prefix(c)?;
+            literal = &literal[c.len_utf8()..];
+        }
+    }
+    Some(text.len() - rest.len())
+}
+
+/// The first offset in `text` at which `literal` matches.
+fn find_literal(literal: &str, text: &str) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .find(|i| match_literal(literal, &text[*i..]).is_some())
+}
+
+/// Decodes a single capture.
+pub trait Scan: Sized {
+    fn scan(text: &str) -> Result<Self, BoxError>;
+}
+
+macro_rules! scan_from_str {
+    ($($t:ty),*) => {
+        $(
+            impl Scan for $t {
+                fn scan(text: &str) -> Result<Self, BoxError> {
+                    Ok(text.trim().parse::<$t>()?)
+                }
+            }
+        )*
+    };
+}
+
+scan_from_str!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char);
+
+impl Scan for String {
+    fn scan(text: &str) -> Result<Self, BoxError> {
+        Ok(text.to_string())
+    }
+}
+
+/// Lists are separated by commas and/or whitespace.
+impl<T: Scan> Scan for Vec<T> {
+    fn scan(text: &str) -> Result<Self, BoxError> {
+        text.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(T::scan)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    template: String,
+    text: String,
+    message: String
+}
+
+impl ScanError {
+    fn template(template: &str, message: &str) -> ScanError {
+        ScanError {
+            template: template.to_string(),
+            text: String::new(),
+            message: message.to_string()
+        }
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} does not match {:?}: {}", self.text, self.template, self.message)
+    }
+}
+
+impl error::Error for ScanError {}
+
+/// Matches `text` against a template and decodes each capture as the listed type,
+/// returning them as a tuple, e.g. `input::scan!(line, "{}: {}" => u64, Vec<u64>)`.
+///
+/// The template must be a string literal. It is parsed the first time each `scan!` runs and
+/// reused after that, so build a [`Pattern`] directly for templates made at runtime.
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $template:literal => $($t:ty),+ $(,)?) => {{
+        static PATTERN: ::std::sync::OnceLock<Result<$crate::Pattern, $crate::ScanError>> = ::std::sync::OnceLock::new();
+        PATTERN.get_or_init(|| $crate::Pattern::new($template))
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(|pattern| {
+                let mut captures = pattern.captures($text)?;
+                let values = ($(captures.decode::<$t>()?,)+);
+                captures.finish()?;
+                Ok(values)
+            })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_templates() {
+        assert_eq!(Pattern::new("{}: {}").unwrap().capture_count(), 2);
+        assert_eq!(Pattern::new("{{}}").unwrap().capture_count(), 0);
+        assert_eq!(
+            Pattern::new("{}{}").unwrap_err().to_string(),
+            "\"\" does not match \"{}{}\": adjacent captures need a literal between them"
+        );
+        assert_eq!(
+            Pattern::new("{} }").unwrap_err().to_string(),
+            "\"\" does not match \"{} }\": unmatched brace, use {{ or }} for a literal brace"
+        );
+    }
+
+    #[test]
+    fn scans_captures() {
+        assert_eq!(scan!("190: 10 19", "{}: {}" => u64, Vec<u64>), Ok((190, vec![10, 19])));
+        assert_eq!(scan!("mul(2,4)", "mul({},{})" => i32, i32), Ok((2, 4)));
+        assert_eq!(scan!("1, 2,3  4", "{}" => Vec<i32>), Ok((vec![1, 2, 3, 4],)));
+        assert_eq!(scan!("{7} is {x}", "{{{}}} is {}" => u8, String), Ok((7, "{x}".to_string())));
+    }
+
+    #[test]
+    fn whitespace_matches_any_run() {
+        assert_eq!(scan!("3   4", "{} {}" => i32, i32), Ok((3, 4)));
+        assert_eq!(scan!("3\t4", "{} {}" => i32, i32), Ok((3, 4)));
+        assert_eq!(scan!("  5 6", "{} {}" => i32, i32), Ok((5, 6)));
+        assert!(scan!("34", "{} {}" => i32, i32).is_err());
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        assert_eq!(
+            scan!("mul(1;2)", "mul({},{})" => i32, i32).unwrap_err().to_string(),
+            "\"mul(1;2)\" does not match \"mul({},{})\": expected \",\" after column 5"
+        );
+        assert_eq!(
+            scan!("sum(1,2)", "mul({},{})" => i32, i32).unwrap_err().to_string(),
+            "\"sum(1,2)\" does not match \"mul({},{})\": expected \"mul(\" at column 1"
+        );
+        assert_eq!(
+            scan!("a 1", "{} {}" => i32, i32).unwrap_err().to_string(),
+            "\"a 1\" does not match \"{} {}\": capture 1 (\"a\"): invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn reports_leftovers() {
+        assert_eq!(
+            scan!("mul(1,2)x", "mul({},{})" => i32, i32).unwrap_err().to_string(),
+            "\"mul(1,2)x\" does not match \"mul({},{})\": unexpected \"x\" at column 9"
+        );
+        assert_eq!(
+            scan!("1 2", "{} {}" => i32).unwrap_err().to_string(),
+            "\"1 2\" does not match \"{} {}\": 1 captures left undecoded"
+        );
+        assert_eq!(
+            scan!("1", "{}" => i32, i32).unwrap_err().to_string(),
+            "\"1\" does not match \"{}\": template has no capture 2"
+        );
+    }
+
+    #[test]
+    fn reuses_the_pattern_across_calls() {
+        let lines = ["1|2", "3|4", "5-6"];
+        let scanned: Vec<_> = lines.iter().map(|line| scan!(line, "{}|{}" => i32, i32)).collect();
+        assert_eq!(scanned[..2], [Ok((1, 2)), Ok((3, 4))]);
+        assert!(scanned[2].is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use std::{io::BufRead, iter::FusedIterator, str::FromStr, vec};
+
+use crate::{BoxError, Error, InputSource, Lines};
+
+/// A run of consecutive non-blank lines, keeping the line numbers from the original input.
+#[derive(Debug, Clone)]
+pub struct Section {
+    path: String,
+    lines: Vec<(usize, String)>
+}
+
+impl Section {
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines.iter().map(|(line_no, line)| (*line_no, line.as_str()))
+    }
+
+    pub fn parse<T>(&self) -> Result<Vec<T>, Error>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>
+    {
+        self.lines()
+            .map(|(line_no, line)| line.parse::<T>().map_err(|e| Error::parse(&self.path, line_no, line, e)))
+            .collect()
+    }
+}
+
+impl IntoIterator for Section {
+    type Item = (usize, String);
+    type IntoIter = vec::IntoIter<(usize, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines.into_iter()
+    }
+}
+
+/// Splits a line stream on blank lines, yielding one [`Section`] per block.
+///
+/// Ends after the first I/O error.
+pub struct Sections<R> {
+    lines: Lines<R>,
+    count: usize,
+    done: bool
+}
+
+impl Sections<Box<dyn BufRead>> {
+    pub fn open<S: Into<InputSource>>(source: S) -> Result<Self, Error> {
+        Ok(Sections::new(Lines::open(source)?))
+    }
+}
+
+impl<R: BufRead> Sections<R> {
+    pub fn new(lines: Lines<R>) -> Self {
+        Sections { lines, count: 0, done: false }
+    }
+
+    /// Decodes the next section as `T`s, failing if the input has no sections left.
+    pub fn parse_next<T>(&mut self) -> Result<Vec<T>, Error>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>
+    {
+        match self.next() {
+            Some(section) => section?.parse(),
+            None => Err(Error::MissingSection {
+                path: self.lines.path().to_string(),
+                section: self.count + 1
+            })
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Sections<R> {
+    type Item = Result<Section, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut lines = Vec::new();
+        for line in self.lines.by_ref() {
+            match line {
+                Ok((_, line)) if line.is_empty() => {
+                    if lines.is_empty() {
+                        continue;
+                    }
+                    break;
+                },
+                Ok(line) => lines.push(line),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        if lines.is_empty() {
+            self.done = true;
+            return None;
+        }
+        self.count += 1;
+        Some(Ok(Section {
+            path: self.lines.path().to_string(),
+            lines
+        }))
+    }
+}
+
+impl<R: BufRead> FusedIterator for Sections<R> {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::*;
+
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("broken"))
+        }
+    }
+
+    #[test]
+    fn sections_end_after_an_error() {
+        let mut sections = Sections::new(Lines::new("broken", BufReader::new(Broken)));
+        assert!(matches!(sections.next(), Some(Err(Error::Io { .. }))));
+        assert!(sections.next().is_none());
+    }
+
+    fn numbered(section: Section) -> Vec<(usize, String)> {
+        section.into_iter().collect()
+    }
+
+    #[test]
+    fn skips_leading_and_repeated_blank_lines() {
+        let mut sections = Sections::open(InputSource::Text("\n\na\nb\n\n\n\nc\n\n")).unwrap();
+        let first = numbered(sections.next().unwrap().unwrap());
+        assert_eq!(first, [(3, "a".to_string()), (4, "b".to_string())]);
+        let second = numbered(sections.next().unwrap().unwrap());
+        assert_eq!(second, [(8, "c".to_string())]);
+        assert!(sections.next().is_none());
+    }
+
+    #[test]
+    fn parse_next_reports_missing_sections() {
+        let mut sections = Sections::open(InputSource::Text("1|2\n\n3,4\n")).unwrap();
+        assert_eq!(sections.parse_next::<String>().unwrap(), ["1|2"]);
+        assert_eq!(sections.parse_next::<String>().unwrap(), ["3,4"]);
+        let error = sections.parse_next::<String>().unwrap_err();
+        assert!(matches!(error, Error::MissingSection { section: 3, .. }));
+        assert_eq!(error.to_string(), "<text>: missing section 3");
+    }
+
+    #[test]
+    fn parse_next_reports_the_bad_line() {
+        let mut sections = Sections::open(InputSource::Text("1\n\n2\nx\n")).unwrap();
+        assert_eq!(sections.parse_next::<u32>().unwrap(), [1]);
+        assert!(matches!(sections.parse_next::<u32>(), Err(Error::Parse { line: 4, .. })));
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fs::File, io::{self, BufRead, BufReader, Read}, path::{Path, PathBuf}};
+
+use crate::Error;
+
+/// Where puzzle input is read from: a file on disk, standard input, or text compiled into the binary.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(&'static str)
+}
+
+impl InputSource {
+    /// A label for the source, used in place of a path in errors.
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "<stdin>".to_string(),
+            InputSource::Text(_) => "<text>".to_string()
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, Error> {
+        match self {
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|e| Error::io(&self.name(), e))?;
+                Ok(Box::new(BufReader::new(file)))
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Text(text) => Ok(Box::new(text.as_bytes()))
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String, Error> {
+        match self {
+            InputSource::Text(text) => Ok(text.to_string()),
+            _ => {
+                let mut data = String::new();
+                self.reader()?
+                    .read_to_string(&mut data)
+                    .map_err(|e| Error::io(&self.name(), e))?;
+                Ok(data)
+            }
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(path: &str) -> Self {
+        InputSource::File(PathBuf::from(path))
+    }
+}
+
+impl From<String> for InputSource {
+    fn from(path: String) -> Self {
+        InputSource::File(PathBuf::from(path))
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        InputSource::File(path.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        InputSource::File(path)
+    }
+}
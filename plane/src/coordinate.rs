@@ -0,0 +1,44 @@
+use core::fmt;
+use std::{hash::Hash, ops::{Add, Neg, Sub}};
+
+/// An integer type usable for [`Point`](crate::Point) and [`Offset`](crate::Offset) components.
+///
+/// Arithmetic between different coordinate types goes through `i128`, which holds every
+/// supported type, so only the final narrowing back into `Self` can fail.
+pub trait Coordinate: Copy + Eq + Ord + Hash + Default + fmt::Debug + fmt::Display {
+    fn to_i128(self) -> i128;
+
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Converts from any other coordinate type, or `None` if the value doesn't fit.
+    fn try_from_coordinate<U: Coordinate>(value: U) -> Option<Self> {
+        Self::from_i128(value.to_i128())
+    }
+}
+
+/// A [`Coordinate`] that can be negative, as needed by offsets.
+pub trait SignedCoordinate: Coordinate + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> {}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl SignedCoordinate for i8 {}
+impl SignedCoordinate for i16 {}
+impl SignedCoordinate for i32 {}
+impl SignedCoordinate for i64 {}
+impl SignedCoordinate for isize {}
@@ -0,0 +1,244 @@
+use crate::{Offset, SignedCoordinate};
+
+/// A relative change of heading.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+    Back
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    pub fn offset(&self) -> Offset {
+        match self {
+            Direction::Up => Offset::from(0, -1),
+            Direction::Right => Offset::from(1, 0),
+            Direction::Down => Offset::from(0, 1),
+            Direction::Left => Offset::from(-1, 0),
+        }
+    }
+
+    /// The direction of a unit offset, or `None` for any other offset.
+    pub fn from_offset<T: SignedCoordinate>(offset: &Offset<T>) -> Option<Direction> {
+        match Direction8::from_offset(offset)? {
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::Left => Some(Direction::Left),
+            _ => None
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate_cw().rotate_cw()
+    }
+
+    pub fn turn(&self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.rotate_ccw(),
+            Turn::Right => self.rotate_cw(),
+            Turn::Back => self.opposite()
+        }
+    }
+}
+
+impl IntoIterator for Direction {
+    type Item = Direction;
+    type IntoIter = DirectionIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DirectionIterator {
+            current: self,
+            count: 0
+        }
+    }
+}
+
+pub struct DirectionIterator {
+    current: Direction,
+    count: usize
+}
+
+impl Iterator for DirectionIterator {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.count < 4 {
+            let direction = self.current;
+
+            self.current = self.current.rotate_cw();
+            self.count += 1;
+
+            return Some(direction);
+        }
+        None
+    }
+}
+
+/// The four cardinal directions plus the diagonals between them.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft
+}
+
+impl Direction8 {
+    pub fn offset(&self) -> Offset {
+        match self {
+            Direction8::Up => Offset::from(0, -1),
+            Direction8::UpRight => Offset::from(1, -1),
+            Direction8::Right => Offset::from(1, 0),
+            Direction8::DownRight => Offset::from(1, 1),
+            Direction8::Down => Offset::from(0, 1),
+            Direction8::DownLeft => Offset::from(-1, 1),
+            Direction8::Left => Offset::from(-1, 0),
+            Direction8::UpLeft => Offset::from(-1, -1),
+        }
+    }
+
+    /// The direction of a unit offset, or `None` for any other offset.
+    pub fn from_offset<T: SignedCoordinate>(offset: &Offset<T>) -> Option<Direction8> {
+        match (offset.dx().to_i128(), offset.dy().to_i128()) {
+            (0, -1) => Some(Direction8::Up),
+            (1, -1) => Some(Direction8::UpRight),
+            (1, 0) => Some(Direction8::Right),
+            (1, 1) => Some(Direction8::DownRight),
+            (0, 1) => Some(Direction8::Down),
+            (-1, 1) => Some(Direction8::DownLeft),
+            (-1, 0) => Some(Direction8::Left),
+            (-1, -1) => Some(Direction8::UpLeft),
+            _ => None
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(self, Direction8::UpRight | Direction8::DownRight | Direction8::DownLeft | Direction8::UpLeft)
+    }
+
+    /// Turns 45° clockwise.
+    pub fn rotate_cw_45(&self) -> Direction8 {
+        match self {
+            Direction8::Up => Direction8::UpRight,
+            Direction8::UpRight => Direction8::Right,
+            Direction8::Right => Direction8::DownRight,
+            Direction8::DownRight => Direction8::Down,
+            Direction8::Down => Direction8::DownLeft,
+            Direction8::DownLeft => Direction8::Left,
+            Direction8::Left => Direction8::UpLeft,
+            Direction8::UpLeft => Direction8::Up,
+        }
+    }
+
+    /// Turns 45° counter-clockwise.
+    pub fn rotate_ccw_45(&self) -> Direction8 {
+        match self {
+            Direction8::Up => Direction8::UpLeft,
+            Direction8::UpRight => Direction8::Up,
+            Direction8::Right => Direction8::UpRight,
+            Direction8::DownRight => Direction8::Right,
+            Direction8::Down => Direction8::DownRight,
+            Direction8::DownLeft => Direction8::Down,
+            Direction8::Left => Direction8::DownLeft,
+            Direction8::UpLeft => Direction8::Left,
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Direction8 {
+        self.rotate_cw_45().rotate_cw_45()
+    }
+
+    pub fn rotate_ccw(&self) -> Direction8 {
+        self.rotate_ccw_45().rotate_ccw_45()
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        self.rotate_cw().rotate_cw()
+    }
+
+    /// Left and right turns are 90°, as for [`Direction::turn`].
+    pub fn turn(&self, turn: Turn) -> Direction8 {
+        match turn {
+            Turn::Left => self.rotate_ccw(),
+            Turn::Right => self.rotate_cw(),
+            Turn::Back => self.opposite()
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+/// Yields all eight directions clockwise, starting from this one.
+impl IntoIterator for Direction8 {
+    type Item = Direction8;
+    type IntoIter = Direction8Iterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Direction8Iterator {
+            current: self,
+            count: 0
+        }
+    }
+}
+
+pub struct Direction8Iterator {
+    current: Direction8,
+    count: usize
+}
+
+impl Iterator for Direction8Iterator {
+    type Item = Direction8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < 8 {
+            let direction = self.current;
+
+            self.current = self.current.rotate_cw_45();
+            self.count += 1;
+
+            return Some(direction);
+        }
+        None
+    }
+}
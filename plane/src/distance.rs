@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+use crate::{Coordinate, Grid, Point, Rect};
+
+impl<T: Coordinate> Point<T> {
+    /// The number of up, right, down or left steps between the points.
+    pub fn manhattan_distance(&self, other: &Point<T>) -> u64 {
+        let (dx, dy) = self.distances(other);
+        u64::try_from(dx + dy).expect("distance fits in u64")
+    }
+
+    /// The number of steps between the points when diagonal steps are allowed too.
+    pub fn chebyshev_distance(&self, other: &Point<T>) -> u64 {
+        let (dx, dy) = self.distances(other);
+        u64::try_from(dx.max(dy)).expect("distance fits in u64")
+    }
+
+    fn distances(&self, other: &Point<T>) -> (u128, u128) {
+        (
+            (self.x.to_i128() - other.x.to_i128()).unsigned_abs(),
+            (self.y.to_i128() - other.y.to_i128()).unsigned_abs()
+        )
+    }
+}
+
+impl<T: Coordinate> Rect<T> {
+    /// The points of the rect at most `radius` up, right, down or left steps from `center`,
+    /// in row-major order. `center` itself may lie outside the rect.
+    pub fn within_manhattan(&self, center: Point<T>, radius: usize) -> impl Iterator<Item = Point<T>> {
+        self.within(center, radius, move |dy| radius - dy)
+    }
+
+    /// The points of the rect in the square reaching `radius` steps from `center` in every
+    /// direction, in row-major order. `center` itself may lie outside the rect.
+    pub fn within_chebyshev(&self, center: Point<T>, radius: usize) -> impl Iterator<Item = Point<T>> {
+        self.within(center, radius, move |_| radius)
+    }
+
+    /// The points of each row `dy` away from `center` reaching `reach(dy)` either side of it,
+    /// clipped to the rect before iterating so the cost doesn't grow with `radius`.
+    fn within<F>(&self, center: Point<T>, radius: usize, reach: F) -> impl Iterator<Item = Point<T>>
+    where
+        F: Fn(usize) -> usize
+    {
+        let (min_x, min_y, end_x, end_y) = self.extent();
+        let (x, y) = (center.x.to_i128(), center.y.to_i128());
+        let radius = radius as i128;
+        let component = |v: i128| T::from_i128(v).expect("point within rect fits in T");
+        ((y - radius).max(min_y)..(y + radius + 1).min(end_y)).flat_map(move |row| {
+            let reach = reach((row - y).unsigned_abs() as usize) as i128;
+            ((x - reach).max(min_x)..(x + reach + 1).min(end_x))
+                .map(move |column| Point { x: component(column), y: component(row) })
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// The fewest up, right, down or left steps from any of `sources` to each cell, moving only
+    /// onto cells where `passable` holds. Sources are always at distance zero, and cells that
+    /// can't be reached are `None`.
+    pub fn distance_field<S, F>(&self, sources: S, mut passable: F) -> Grid<Option<u32>>
+    where
+        S: IntoIterator<Item = Point>,
+        F: FnMut(&T) -> bool
+    {
+        let mut distances = Grid::filled(self.bounds(), None);
+        let mut pending = VecDeque::new();
+        for source in sources {
+            if let Some(distance @ None) = distances.get_mut(source) {
+                *distance = Some(0);
+                pending.push_back(source);
+            }
+        }
+        while let Some(point) = pending.pop_front() {
+            let next = distances[point].expect("queued points have a distance") + 1;
+            for (neighbor, cell) in self.neighbors4(point) {
+                if distances[neighbor].is_none() && passable(cell) {
+                    distances[neighbor] = Some(next);
+                    pending.push_back(neighbor);
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    #[test]
+    fn within_manhattan_is_a_clipped_diamond() {
+        let rect = Rect::new(5, 5);
+        let points: Vec<_> = rect.within_manhattan(Point { x: 0, y: 1 }, 2).collect();
+        assert_eq!(points.len(), 8);
+        assert!(points.iter().all(|point| point.manhattan_distance(&Point { x: 0, y: 1 }) <= 2));
+        assert_eq!(Rect::new(100, 100).within_manhattan(Point { x: 50, y: 50 }, 20).count(), 841);
+    }
+
+    #[test]
+    fn within_a_radius_of_an_outside_center() {
+        let rect = Rect::at(Point { x: 10, y: 10 }, 3, 3);
+        assert_eq!(rect.within_chebyshev(Point { x: 0, y: 0 }, 9).count(), 0);
+        assert_eq!(rect.within_chebyshev(Point { x: 0, y: 0 }, 10).count(), 1);
+        let points: Vec<_> = rect.within_manhattan(Point { x: 11, y: 0 }, 11).collect();
+        assert_eq!(points, [Point { x: 10, y: 10 }, Point { x: 11, y: 10 }, Point { x: 12, y: 10 }, Point { x: 11, y: 11 }]);
+    }
+
+    #[test]
+    fn huge_radius_on_a_small_rect() {
+        let started = Instant::now();
+        assert_eq!(Rect::new(3, 3).within_chebyshev(Point { x: 1, y: 1 }, 1_000_000_000).count(), 9);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn distance_field_from_many_sources() {
+        let grid = Grid::from_rows(["..#..", "..#..", "....."].iter().map(|row| row.chars().collect()).collect()).unwrap();
+        let distances = grid.distance_field([Point { x: 0, y: 0 }, Point { x: 4, y: 0 }], |c| *c != '#');
+        assert_eq!(distances[Point { x: 1, y: 1 }], Some(2));
+        assert_eq!(distances[Point { x: 2, y: 2 }], Some(4));
+        assert_eq!(distances[Point { x: 2, y: 0 }], None);
+    }
+}
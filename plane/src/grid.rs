@@ -0,0 +1,275 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Point, Rect};
+
+/// Dense, row-major storage for one value per point of a [`Rect`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    bounds: Rect,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// Returns `None` unless there is exactly one cell per point of `bounds`.
+    pub fn new(bounds: Rect, cells: Vec<T>) -> Option<Grid<T>> {
+        if cells.len() != bounds.area() {
+            return None;
+        }
+        Some(Grid { bounds, cells })
+    }
+
+    /// Returns `None` if the rows are not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Grid::new(Rect::new(width, height), rows.into_iter().flatten().collect())
+    }
+
+    pub fn filled(bounds: Rect, value: T) -> Grid<T> where T: Clone {
+        Grid {
+            bounds,
+            cells: vec![value; bounds.area()]
+        }
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let bounds = self.bounds;
+        self.cells.iter()
+            .enumerate()
+            .map(move |(i, cell)| (point_at(bounds, i), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let bounds = self.bounds;
+        self.cells.iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (point_at(bounds, i), cell))
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds.neighbors4(point).map(move |neighbor| (neighbor, &self[neighbor]))
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.bounds.neighbors8(point).map(move |neighbor| (neighbor, &self[neighbor]))
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U> where F: FnMut(&T) -> U {
+        Grid {
+            bounds: self.bounds,
+            cells: self.cells.iter().map(&mut f).collect()
+        }
+    }
+
+    /// A borrowed view of the cells within `rect`, or `None` unless `rect` lies within the grid.
+    ///
+    /// The view keeps the grid's coordinates, so its bounds start at `rect.origin`.
+    pub fn view(&self, rect: Rect) -> Option<GridView<'_, T>> {
+        GridView { grid: self, bounds: self.bounds }.view(rect)
+    }
+
+    /// The grid turned a quarter clockwise, see [`Rect::rotate_cw_point`].
+    pub fn rotate_cw(&self) -> Grid<T> where T: Clone {
+        let bounds = self.bounds.transposed();
+        self.remap(bounds, |point| bounds.rotate_ccw_point(point))
+    }
+
+    /// The grid turned a quarter anticlockwise, see [`Rect::rotate_ccw_point`].
+    pub fn rotate_ccw(&self) -> Grid<T> where T: Clone {
+        let bounds = self.bounds.transposed();
+        self.remap(bounds, |point| bounds.rotate_cw_point(point))
+    }
+
+    /// The grid mirrored left to right, see [`Rect::flip_horizontal_point`].
+    pub fn flip_horizontal(&self) -> Grid<T> where T: Clone {
+        let bounds = self.bounds;
+        self.remap(bounds, |point| bounds.flip_horizontal_point(point))
+    }
+
+    /// The grid mirrored top to bottom, see [`Rect::flip_vertical_point`].
+    pub fn flip_vertical(&self) -> Grid<T> where T: Clone {
+        let bounds = self.bounds;
+        self.remap(bounds, |point| bounds.flip_vertical_point(point))
+    }
+
+    /// The grid mirrored along its main diagonal, see [`Rect::transpose_point`].
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        let bounds = self.bounds.transposed();
+        self.remap(bounds, |point| bounds.transpose_point(point))
+    }
+
+    /// Builds a grid over `bounds`, copying each cell from the point `source` maps it back to.
+    fn remap<F>(&self, bounds: Rect, source: F) -> Grid<T>
+    where
+        T: Clone,
+        F: Fn(Point) -> Option<Point>
+    {
+        Grid {
+            bounds,
+            cells: bounds.points()
+                .map(|point| self[source(point).expect("transform maps the bounds onto themselves")].clone())
+                .collect()
+        }
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        if self.bounds.contains(&point) {
+            let origin = self.bounds.origin;
+            Some((point.y - origin.y) * self.bounds.width + (point.x - origin.x))
+        } else {
+            None
+        }
+    }
+
+    fn out_of_bounds(&self, point: Point) -> ! {
+        panic!("point {point} not within grid of {}x{}", self.bounds.width, self.bounds.height)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => self.out_of_bounds(point)
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => self.out_of_bounds(point)
+        }
+    }
+}
+
+fn point_at(bounds: Rect, index: usize) -> Point {
+    Point {
+        x: bounds.origin.x + index % bounds.width,
+        y: bounds.origin.y + index / bounds.width
+    }
+}
+
+/// A borrowed window onto part of a [`Grid`], indexed with the grid's own points.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    bounds: Rect
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        if self.bounds.contains(&point) { self.grid.get(point) } else { None }
+    }
+
+    /// A narrower view, or `None` unless `rect` lies within this one.
+    pub fn view(&self, rect: Rect) -> Option<GridView<'a, T>> {
+        if !rect.is_empty() && self.bounds.intersection(&rect) != Some(rect) {
+            return None;
+        }
+        Some(GridView { grid: self.grid, bounds: rect })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> {
+        let grid = self.grid;
+        self.bounds.points().map(move |point| (point, &grid[point]))
+    }
+
+    /// Copies the viewed cells into a grid of their own, keeping their coordinates.
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        Grid {
+            bounds: self.bounds,
+            cells: self.iter().map(|(_, cell)| cell.clone()).collect()
+        }
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Index<Point> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("point {point} not within view of {}x{}", self.bounds.width, self.bounds.height)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = letters(&["ab", "cd", "ef"]);
+        assert_eq!(grid.rotate_cw(), letters(&["eca", "fdb"]));
+        assert_eq!(grid.rotate_ccw(), letters(&["bdf", "ace"]));
+        assert_eq!(grid.flip_horizontal(), letters(&["ba", "dc", "fe"]));
+        assert_eq!(grid.flip_vertical(), letters(&["ef", "cd", "ab"]));
+        assert_eq!(grid.transpose(), letters(&["ace", "bdf"]));
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_horizontal().flip_vertical());
+    }
+
+    #[test]
+    fn transforms_empty_grids() {
+        let grid: Grid<char> = Grid::new(Rect::new(0, 3), Vec::new()).unwrap();
+        assert_eq!(grid.rotate_cw().bounds(), Rect::new(3, 0));
+        assert_eq!(grid.transpose().bounds(), Rect::new(3, 0));
+        assert_eq!(grid.flip_vertical().bounds(), Rect::new(0, 3));
+    }
+
+    #[test]
+    fn views_keep_grid_coordinates() {
+        let grid = letters(&["abc", "def", "ghi"]);
+        let view = grid.view(Rect::at(Point { x: 1, y: 1 }, 2, 2)).unwrap();
+        assert_eq!(view.get(Point { x: 1, y: 1 }), Some(&'e'));
+        assert_eq!(view.get(Point { x: 0, y: 0 }), None);
+        assert_eq!(view[Point { x: 2, y: 2 }], 'i');
+        assert_eq!(view.iter().map(|(_, c)| *c).collect::<String>(), "efhi");
+
+        let narrower = view.view(Rect::at(Point { x: 2, y: 1 }, 1, 2)).unwrap();
+        assert_eq!(narrower.iter().map(|(_, c)| *c).collect::<String>(), "fi");
+        assert!(view.view(Rect::at(Point { x: 0, y: 1 }, 2, 2)).is_none());
+        assert!(grid.view(Rect::at(Point { x: 2, y: 2 }, 2, 1)).is_none());
+
+        let copy = narrower.to_grid();
+        assert_eq!(copy.bounds(), Rect::at(Point { x: 2, y: 1 }, 1, 2));
+        assert_eq!(copy[Point { x: 2, y: 2 }], 'i');
+    }
+}
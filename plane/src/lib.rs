@@ -1,239 +1,192 @@
 use core::fmt;
-
-#[derive(Debug, Default)]
-enum Transform {
-    #[default]
-    None,
-    Decrease(usize),
-    Increase(usize)
-}
-
-impl Transform {
-    fn from(v: i32) -> Transform {
-        if v.is_positive() {
-            Transform::Increase(v as usize)
-        } else if v.is_negative() {
-            Transform::Decrease((v * -1) as usize)
-        } else {
-            Transform::None
+use std::ops::{Add, Mul, Neg, Sub};
+
+mod coordinate;
+mod direction;
+mod distance;
+mod grid;
+mod line;
+mod pattern;
+mod ray;
+mod rect;
+mod region;
+mod search;
+mod walker;
+
+pub use coordinate::{Coordinate, SignedCoordinate};
+pub use direction::{Direction, Direction8, Direction8Iterator, DirectionIterator, Turn};
+pub use grid::{Grid, GridView};
+pub use line::Bresenham;
+pub use pattern::{find_stencil, find_word, Orientation, Stencil, StencilMatch, WordMatch};
+pub use ray::{Ray, Until};
+pub use rect::{Rect, RectIterator};
+pub use region::{Edge, Region, Regions};
+pub use search::{astar, bfs, dijkstra, GridMoves, ShortestPaths, TurningMoves};
+pub use walker::{simulate, Outcome, Simulation, Walk, Walker};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Point<T = usize> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T: Coordinate> Point<T> {
+    /// The offset that moves `other` onto `self`, or `None` if either component doesn't fit
+    /// in an `i64`, as can happen with far apart `u64` or `i64` points.
+    pub fn checked_offset_from(&self, other: &Point<T>) -> Option<Offset<i64>> {
+        Some(Offset {
+            x: i64::from_i128(self.x.to_i128() - other.x.to_i128())?,
+            y: i64::from_i128(self.y.to_i128() - other.y.to_i128())?
+        })
+    }
+
+    /// The offset that moves `other` onto `self`.
+    ///
+    /// Panics if either component doesn't fit in an `i64`, use [`Point::checked_offset_from`]
+    /// for points that may be further apart.
+    pub fn offset_from(&self, other: &Point<T>) -> Offset<i64> {
+        match self.checked_offset_from(other) {
+            Some(offset) => offset,
+            None => panic!("offset from {other} to {self} does not fit in i64")
         }
     }
 
-    fn apply(&self, value: usize) -> Option<usize> {
-        return match self {
-            Transform::None => Some(value),
-            Transform::Decrease(amount) => {
-                let (_, overflow) = value.overflowing_sub(*amount);
-                if overflow { None }
-                else { Some(value - amount) }
-            },
-            Transform::Increase(amount) =>{
-                let (_, overflow) = value.overflowing_add(*amount);
-                if overflow { None }
-                else { Some(value + amount) }
-            },
-        }
+    /// Moves by `offset`, or `None` if the result doesn't fit in `T`, e.g. below zero for `usize`.
+    pub fn checked_add<D: SignedCoordinate>(&self, offset: &Offset<D>) -> Option<Point<T>> {
+        Some(Point {
+            x: T::from_i128(self.x.to_i128() + offset.x.to_i128())?,
+            y: T::from_i128(self.y.to_i128() + offset.y.to_i128())?
+        })
     }
 
-    fn reverse(&self) -> Transform {
-        match self {
-            Transform::None => Transform::None,
-            Transform::Decrease(v) => Transform::Increase(*v),
-            Transform::Increase(v) => Transform::Decrease(*v),
-        }
+    pub fn checked_sub<D: SignedCoordinate>(&self, offset: &Offset<D>) -> Option<Point<T>> {
+        Some(Point {
+            x: T::from_i128(self.x.to_i128() - offset.x.to_i128())?,
+            y: T::from_i128(self.y.to_i128() - offset.y.to_i128())?
+        })
     }
-}
 
-impl fmt::Display for Transform {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Transform::None => write!(f, "0"),
-            Transform::Decrease(v) => write!(f, "-{v}"),
-            Transform::Increase(v) => write!(f, "{v}"),
-        }
+    /// Converts to another coordinate type, or `None` if either component doesn't fit.
+    pub fn try_cast<U: Coordinate>(&self) -> Option<Point<U>> {
+        Some(Point {
+            x: U::try_from_coordinate(self.x)?,
+            y: U::try_from_coordinate(self.y)?
+        })
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub struct Point {
-    pub x: usize,
-    pub y: usize
-}
-
-impl Point {
-    pub fn offset_from(&self, other: &Point) -> Offset {
-        let (_, x_overflow) = self.x.overflowing_sub(other.x);
-        let x = if x_overflow { Transform::Decrease(self.x.abs_diff(other.x)) } else { Transform::Increase(self.x.abs_diff(other.x)) };
-
-        let (_, y_overflow) = self.y.overflowing_sub(other.y);
-        let y = if y_overflow { Transform::Decrease(self.y.abs_diff(other.y)) } else { Transform::Increase(self.y.abs_diff(other.y)) };
+/// Panics if the result doesn't fit, use [`Point::checked_add`] near the edge of `T`.
+impl<T: Coordinate, D: SignedCoordinate> Add<Offset<D>> for Point<T> {
+    type Output = Point<T>;
 
-        Offset { x, y }
-    }
-}
-
-impl fmt::Display for Point {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+    fn add(self, offset: Offset<D>) -> Self::Output {
+        match self.checked_add(&offset) {
+            Some(point) => point,
+            None => panic!("{self} + {offset} is out of range")
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Rect {
-    pub width: usize,
-    pub height: usize
-}
+impl<T: Coordinate, D: SignedCoordinate> Sub<Offset<D>> for Point<T> {
+    type Output = Point<T>;
 
-impl Rect {
-    pub fn contains(&self, point: &Point) -> bool {
-        point.x < self.width && point.y < self.height
+    fn sub(self, offset: Offset<D>) -> Self::Output {
+        match self.checked_sub(&offset) {
+            Some(point) => point,
+            None => panic!("{self} - {offset} is out of range")
+        }
     }
 }
 
-impl IntoIterator for Rect {
-    type Item = (Point, bool);
-    type IntoIter = RectIterator;
+/// Panics if the offset doesn't fit, see [`Point::offset_from`].
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Offset<i64>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        RectIterator {
-            rect: self,
-            current_x: 0,
-            current_y: 0
-        }
+    fn sub(self, other: Point<T>) -> Self::Output {
+        self.offset_from(&other)
     }
 }
 
-pub struct RectIterator {
-    rect: Rect,
-    current_x: usize,
-    current_y: usize
-}
-
-impl Iterator for RectIterator {
-    type Item = (Point, bool);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut overflow = false;
-        if self.current_x >= self.rect.width {
-            self.current_x = 0;
-            self.current_y += 1;
-            overflow = true;
-        }
-
-        if self.current_y >= self.rect.height {
-            return None;
-        }
-
-        let point = Point {
-            x: self.current_x,
-            y: self.current_y
-        };
-        self.current_x += 1;
-        return Some((point, overflow));
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
     }
 }
 
-#[derive(Debug, Default)]
-pub struct Offset {
-    x: Transform,
-    y: Transform
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset<T = i64> {
+    x: T,
+    y: T
 }
 
-impl Offset {
-    pub fn from(x: i32, y: i32) -> Offset {
-        Offset {
-            x: Transform::from(x),
-            y: Transform::from(y)
-        }
+impl<T: SignedCoordinate> Offset<T> {
+    pub fn from(x: T, y: T) -> Offset<T> {
+        Offset { x, y }
     }
 
-    pub fn apply(&self, point: &Point) -> Option<Point> {
-        let x = self.x.apply(point.x)?;
-        let y = self.y.apply(point.y)?;
+    pub fn dx(&self) -> T {
+        self.x
+    }
 
-        return Some(Point { x, y });
+    pub fn dy(&self) -> T {
+        self.y
     }
 
-    // TODO Move this to another abstraction representing the Plane, get point from Plane
-    pub fn apply_within(&self, point: &Point, limit: &Point) -> Option<Point> {
-        let x = self.x.apply(point.x)?;
-        let y = self.y.apply(point.y)?;
-        if x > limit.x || y > limit.y {
-            return None;
-        } else {
-            return Some(Point { x, y })
-        }
+    pub fn apply<P: Coordinate>(&self, point: &Point<P>) -> Option<Point<P>> {
+        point.checked_add(self)
     }
 
-    pub fn inverse(&self) -> Offset {
-        return Offset { x: self.x.reverse(), y: self.y.reverse() };
+    pub fn inverse(&self) -> Offset<T> {
+        Offset { x: -self.x, y: -self.y }
     }
-}
 
-impl fmt::Display for Offset {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+    /// Converts to another signed coordinate type, or `None` if either component doesn't fit.
+    pub fn try_cast<U: SignedCoordinate>(&self) -> Option<Offset<U>> {
+        Some(Offset {
+            x: U::try_from_coordinate(self.x)?,
+            y: U::try_from_coordinate(self.y)?
+        })
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left
-}
+impl<T: SignedCoordinate> Add for Offset<T> {
+    type Output = Offset<T>;
 
-impl Direction {
-    pub fn offset(&self) -> Offset {
-        match self {
-            Direction::Up => Offset { y: Transform::Decrease(1), ..Default::default() },
-            Direction::Right => Offset { x: Transform::Increase(1), ..Default::default() },
-            Direction::Down => Offset { y: Transform::Increase(1), ..Default::default() },
-            Direction::Left => Offset { x: Transform::Decrease(1), ..Default::default() },
-        }
-    }
-
-    pub fn rotate_cw(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
+    fn add(self, other: Offset<T>) -> Self::Output {
+        Offset { x: self.x + other.x, y: self.y + other.y }
     }
 }
 
-impl IntoIterator for Direction {
-    type Item = Direction;
-    type IntoIter = DirectionIterator;
+impl<T: SignedCoordinate> Sub for Offset<T> {
+    type Output = Offset<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        DirectionIterator {
-            current: self,
-            count: 0
-        }
+    fn sub(self, other: Offset<T>) -> Self::Output {
+        Offset { x: self.x - other.x, y: self.y - other.y }
     }
 }
 
-pub struct DirectionIterator {
-    current: Direction,
-    count: usize
-}
+impl<T: SignedCoordinate> Mul<i64> for Offset<T> {
+    type Output = Offset<T>;
 
-impl Iterator for DirectionIterator {
-    type Item = Direction;
-
-    fn next(&mut self) -> Option<Self::Item> {
+    fn mul(self, factor: i64) -> Self::Output {
+        let scale = |v: T| match T::from_i128(v.to_i128() * factor as i128) {
+            Some(scaled) => scaled,
+            None => panic!("{self} * {factor} is out of range")
+        };
+        Offset { x: scale(self.x), y: scale(self.y) }
+    }
+}
 
-        if self.count < 4 {
-            let direction = self.current;
+impl<T: SignedCoordinate> Neg for Offset<T> {
+    type Output = Offset<T>;
 
-            self.current = self.current.rotate_cw();
-            self.count += 1;
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
 
-            return Some(direction);
-        }
-        return None;
+impl<T: fmt::Display> fmt::Display for Offset<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
     }
 }
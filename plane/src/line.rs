@@ -0,0 +1,135 @@
+use std::marker::PhantomData;
+
+use crate::{Coordinate, Offset, Point, SignedCoordinate};
+
+impl<T: SignedCoordinate> Offset<T> {
+    /// The smallest offset in the same direction, with both components divided by their
+    /// greatest common divisor. A zero offset stays zero.
+    pub fn reduced(&self) -> Offset<T> {
+        let (dx, dy) = (self.dx().to_i128(), self.dy().to_i128());
+        let divisor = gcd(dx.unsigned_abs(), dy.unsigned_abs()).max(1) as i128;
+        let component = |v: i128| T::from_i128(v / divisor).expect("reduced component fits in T");
+        Offset::from(component(dx), component(dy))
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Every point with integer coordinates on the segment from `self` to `other`, both included.
+    ///
+    /// Works in `i128` throughout, so it never panics even when the points are too far apart
+    /// for [`Point::offset_from`].
+    pub fn lattice_points_between(&self, other: &Point<T>) -> impl Iterator<Item = Point<T>> {
+        let (start_x, start_y) = (self.x.to_i128(), self.y.to_i128());
+        let (dx, dy) = (other.x.to_i128() - start_x, other.y.to_i128() - start_y);
+        let steps = gcd(dx.unsigned_abs(), dy.unsigned_abs());
+        let divisor = steps.max(1) as i128;
+        let (step_x, step_y) = (dx / divisor, dy / divisor);
+        let component = |v: i128| T::from_i128(v).expect("lattice point lies between two points of T");
+        (0..=steps).map(move |i| Point {
+            x: component(start_x + i as i128 * step_x),
+            y: component(start_y + i as i128 * step_y)
+        })
+    }
+}
+
+/// Rasterizes the segment between two points with Bresenham's algorithm, yielding a connected
+/// run of points from `from` to `to`, both included.
+#[derive(Debug, Clone)]
+pub struct Bresenham<T = usize> {
+    x: i128,
+    y: i128,
+    end_x: i128,
+    end_y: i128,
+    dx: i128,
+    dy: i128,
+    step_x: i128,
+    step_y: i128,
+    error: i128,
+    done: bool,
+    point: PhantomData<T>
+}
+
+impl<T: Coordinate> Bresenham<T> {
+    pub fn new(from: Point<T>, to: Point<T>) -> Bresenham<T> {
+        let (x, y) = (from.x.to_i128(), from.y.to_i128());
+        let (end_x, end_y) = (to.x.to_i128(), to.y.to_i128());
+        let dx = (end_x - x).abs();
+        let dy = -(end_y - y).abs();
+        Bresenham {
+            x,
+            y,
+            end_x,
+            end_y,
+            dx,
+            dy,
+            step_x: if x < end_x { 1 } else { -1 },
+            step_y: if y < end_y { 1 } else { -1 },
+            error: dx + dy,
+            done: false,
+            point: PhantomData
+        }
+    }
+}
+
+impl<T: Coordinate> Iterator for Bresenham<T> {
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let point = Point {
+            x: T::from_i128(self.x).expect("point on segment fits in T"),
+            y: T::from_i128(self.y).expect("point on segment fits in T")
+        };
+
+        if self.x == self.end_x && self.y == self.end_y {
+            self.done = true;
+        } else {
+            let doubled = 2 * self.error;
+            if doubled >= self.dy {
+                self.error += self.dy;
+                self.x += self.step_x;
+            }
+            if doubled <= self.dx {
+                self.error += self.dx;
+                self.y += self.step_y;
+            }
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn far_apart_points_have_no_offset() {
+        let (far, origin) = (Point::<u64> { x: u64::MAX, y: 0 }, Point::<u64> { x: 0, y: 0 });
+        assert_eq!(far.checked_offset_from(&origin), None);
+        assert_eq!(Point::<u64> { x: 3, y: 1 }.checked_offset_from(&origin), Some(Offset::from(3, 1)));
+    }
+
+    #[test]
+    fn lattice_points_between_far_apart_points() {
+        let (origin, far) = (Point::<u64> { x: 0, y: 0 }, Point::<u64> { x: u64::MAX, y: 0 });
+        let points: Vec<_> = origin.lattice_points_between(&far).take(2).collect();
+        assert_eq!(points, [origin, Point { x: 1, y: 0 }]);
+
+        let far = Point::<u64> { x: u64::MAX - 1, y: u64::MAX };
+        let points: Vec<_> = origin.lattice_points_between(&far).collect();
+        assert_eq!(points, [origin, far]);
+    }
+
+    #[test]
+    fn lattice_points_between_nearby_points() {
+        let points: Vec<_> = Point { x: 1, y: 1 }.lattice_points_between(&Point { x: 7, y: 4 }).collect();
+        assert_eq!(points, [Point { x: 1, y: 1 }, Point { x: 3, y: 2 }, Point { x: 5, y: 3 }, Point { x: 7, y: 4 }]);
+        assert_eq!(Point { x: 2, y: 2 }.lattice_points_between(&Point { x: 2, y: 2 }).count(), 1);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Direction8, Grid, Point, Ray, Rect};
+
+/// One of the eight ways to lay a pattern down: mirrored left to right or not, then turned
+/// clockwise by `quarter_turns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub flipped: bool,
+    pub quarter_turns: u8
+}
+
+impl Orientation {
+    /// The pattern as given.
+    pub const IDENTITY: Orientation = Orientation { flipped: false, quarter_turns: 0 };
+
+    /// Every rotation, then every rotation of the mirror image.
+    pub fn all() -> impl Iterator<Item = Orientation> {
+        [false, true].into_iter()
+            .flat_map(|flipped| (0..4).map(move |quarter_turns| Orientation { flipped, quarter_turns }))
+    }
+
+    pub fn apply<T: Clone>(&self, grid: &Grid<T>) -> Grid<T> {
+        let mut grid = if self.flipped { grid.flip_horizontal() } else { grid.clone() };
+        for _ in 0..self.quarter_turns % 4 {
+            grid = grid.rotate_cw();
+        }
+        grid
+    }
+}
+
+/// A word found by [`find_word`], read from `start` towards `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WordMatch {
+    pub start: Point,
+    pub direction: Direction8
+}
+
+/// Every occurrence of `word` along a straight line in any of the eight directions.
+///
+/// Occurrences sharing a start but running in different directions are reported separately,
+/// so a one-letter word matches eight times per cell.
+pub fn find_word<T: PartialEq>(grid: &Grid<T>, word: &[T]) -> Vec<WordMatch> {
+    if word.is_empty() {
+        return Vec::new();
+    }
+    let bounds = grid.bounds();
+    bounds.points()
+        .flat_map(|start| Direction8::Up.into_iter().map(move |direction| WordMatch { start, direction }))
+        .filter(|candidate| {
+            let mut ray = Ray::new(candidate.start, candidate.direction.offset()).include_origin().within(bounds);
+            word.iter().all(|letter| ray.next().is_some_and(|point| grid[point] == *letter))
+        })
+        .collect()
+}
+
+/// A rectangular pattern where `None` cells match anything.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stencil<T> {
+    cells: Grid<Option<T>>
+}
+
+impl<T> Stencil<T> {
+    pub fn new(cells: Grid<Option<T>>) -> Stencil<T> {
+        Stencil { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<Option<T>> {
+        &self.cells
+    }
+}
+
+impl Stencil<char> {
+    /// Reads one row per line, with `wildcard` matching any letter. Returns `None` if the
+    /// rows are not all the same width.
+    pub fn parse(text: &str, wildcard: char) -> Option<Stencil<char>> {
+        let rows = text.lines()
+            .map(|line| line.chars().map(|c| if c == wildcard { None } else { Some(c) }).collect())
+            .collect();
+        Some(Stencil::new(Grid::from_rows(rows)?))
+    }
+}
+
+impl<T: Clone + PartialEq> Stencil<T> {
+    /// Each distinct way of laying the stencil down, paired with the first orientation that
+    /// produces it. Symmetric stencils yield fewer than eight.
+    pub fn orientations(&self) -> Vec<(Orientation, Stencil<T>)> {
+        let mut distinct: Vec<(Orientation, Stencil<T>)> = Vec::new();
+        for orientation in Orientation::all() {
+            let oriented = Stencil::new(orientation.apply(&self.cells));
+            if !distinct.iter().any(|(_, seen)| *seen == oriented) {
+                distinct.push((orientation, oriented));
+            }
+        }
+        distinct
+    }
+
+    /// Whether the stencil, as laid down, matches `grid` with its top-left cell at `origin`.
+    pub fn matches_at(&self, grid: &Grid<T>, origin: Point) -> bool {
+        let window = Rect::at(origin, self.cells.bounds().width, self.cells.bounds().height);
+        let Some(view) = grid.view(window) else {
+            return false;
+        };
+        let offset = origin - self.cells.bounds().origin;
+        self.cells.iter().all(|(point, cell)| {
+            cell.as_ref().is_none_or(|expected| view[point + offset] == *expected)
+        })
+    }
+}
+
+/// A stencil found by [`find_stencil`], laid down with its top-left cell at `origin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StencilMatch {
+    pub origin: Point,
+    pub orientation: Orientation
+}
+
+/// Every placement of `stencil` in `grid` under any rotation or reflection.
+///
+/// Orientations that look the same are only tried once, so a symmetric stencil isn't counted
+/// twice at the same place.
+pub fn find_stencil<T: Clone + PartialEq>(grid: &Grid<T>, stencil: &Stencil<T>) -> Vec<StencilMatch> {
+    stencil.orientations()
+        .into_iter()
+        .flat_map(|(orientation, oriented)| {
+            grid.bounds().points()
+                .filter(|origin| oriented.matches_at(grid, *origin))
+                .map(|origin| StencilMatch { origin, orientation })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+    }
+
+    fn word_search() -> Grid<char> {
+        letters(&[
+            "MMMSXXMASM", "MSAMXMSMSA", "AMXSXMAAMM", "MSAMASMSMX", "XMASAMXAMM",
+            "XXAMMXXAMA", "SMSMSASXSS", "SAXAMASAAA", "MAMMMXMMMM", "MXMXAXMASX"
+        ])
+    }
+
+    #[test]
+    fn finds_words_in_every_direction() {
+        let word: Vec<char> = "XMAS".chars().collect();
+        assert_eq!(find_word(&word_search(), &word).len(), 18);
+
+        let found = find_word(&letters(&["SAMX"]), &word);
+        assert_eq!(found, [WordMatch { start: Point { x: 3, y: 0 }, direction: Direction8::Left }]);
+        assert_eq!(find_word(&letters(&["ab", "cd"]), &['a']).len(), 8);
+        assert!(find_word(&letters(&["ab"]), &[]).is_empty());
+    }
+
+    #[test]
+    fn finds_stencils_in_every_orientation() {
+        let cross = Stencil::parse("M.S\n.A.\nM.S", '.').unwrap();
+        assert_eq!(find_stencil(&word_search(), &cross).len(), 9);
+
+        let found = find_stencil(&letters(&["S.S", ".A.", "M.M"]), &cross);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].origin, Point { x: 0, y: 0 });
+        let laid_down = Stencil::parse("S.S\n.A.\nM.M", '.').unwrap();
+        assert_eq!(found[0].orientation.apply(cross.cells()), *laid_down.cells());
+    }
+
+    #[test]
+    fn symmetric_stencils_have_fewer_orientations() {
+        assert_eq!(Stencil::parse("M.S\n.A.\nM.S", '.').unwrap().orientations().len(), 4);
+        assert_eq!(Stencil::parse("ab\nba", '.').unwrap().orientations().len(), 2);
+        assert_eq!(Stencil::parse("a", '.').unwrap().orientations().len(), 1);
+        assert_eq!(Stencil::parse("ab\nc.", '.').unwrap().orientations().len(), 8);
+        assert!(Stencil::parse("ab\nc", '.').is_none());
+    }
+
+    #[test]
+    fn stencils_stay_within_the_grid() {
+        let stencil = Stencil::parse("ab", '.').unwrap();
+        let grid = letters(&["xa", "ab"]);
+        assert!(stencil.matches_at(&grid, Point { x: 0, y: 1 }));
+        assert!(!stencil.matches_at(&grid, Point { x: 1, y: 0 }));
+        assert!(!stencil.matches_at(&grid, Point { x: 5, y: 5 }));
+        assert!(find_stencil(&letters(&["a"]), &stencil).is_empty());
+    }
+
+    #[test]
+    fn empty_grids_match_nothing() {
+        let empty: Grid<char> = Grid::new(Rect::new(0, 2), Vec::new()).unwrap();
+        assert!(find_word(&empty, &['a']).is_empty());
+        assert!(find_stencil(&empty, &Stencil::parse("a", '.').unwrap()).is_empty());
+    }
+}
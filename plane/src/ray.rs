@@ -0,0 +1,109 @@
+use crate::{Coordinate, Offset, Point, Rect, SignedCoordinate};
+
+/// The points reached by repeatedly stepping from an origin by a fixed offset.
+///
+/// The origin itself is skipped unless [`Ray::include_origin`] is used. Without bounds or a
+/// maximum length the ray only ends once a step no longer fits in `T`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray<T = usize, D = i64> {
+    origin: Point<T>,
+    next: Option<Point<T>>,
+    step: Offset<D>,
+    bounds: Option<Rect<T>>,
+    remaining: Option<usize>
+}
+
+impl<T: Coordinate, D: SignedCoordinate> Ray<T, D> {
+    pub fn new(origin: Point<T>, step: Offset<D>) -> Ray<T, D> {
+        Ray {
+            origin,
+            next: origin.checked_add(&step),
+            step,
+            bounds: None,
+            remaining: None
+        }
+    }
+
+    /// Starts the ray at its origin rather than one step away from it.
+    pub fn include_origin(mut self) -> Ray<T, D> {
+        self.next = Some(self.origin);
+        self
+    }
+
+    /// Ends the ray at the first point outside `rect`.
+    pub fn within(mut self, rect: Rect<T>) -> Ray<T, D> {
+        self.bounds = Some(rect);
+        self
+    }
+
+    /// Yields at most `length` points.
+    pub fn max_length(mut self, length: usize) -> Ray<T, D> {
+        self.remaining = Some(length);
+        self
+    }
+
+    /// Ends the ray just before the first point matching `predicate`, see [`Until::blocker`].
+    pub fn until<F>(self, predicate: F) -> Until<T, D, F> where F: FnMut(&Point<T>) -> bool {
+        Until {
+            ray: self,
+            predicate,
+            blocker: None
+        }
+    }
+}
+
+impl<T: Coordinate, D: SignedCoordinate> Iterator for Ray<T, D> {
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let point = self.next?;
+        if self.bounds.is_some_and(|bounds| !bounds.contains(&point)) {
+            self.next = None;
+            return None;
+        }
+
+        self.next = point.checked_add(&self.step);
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(point)
+    }
+}
+
+/// A [`Ray`] that stops before the first point matching a predicate.
+pub struct Until<T, D, F> {
+    ray: Ray<T, D>,
+    predicate: F,
+    blocker: Option<Point<T>>
+}
+
+impl<T: Coordinate, D, F> Until<T, D, F> {
+    /// The point that ended the ray, or `None` if it ended for any other reason.
+    pub fn blocker(&self) -> Option<Point<T>> {
+        self.blocker
+    }
+}
+
+impl<T, D, F> Iterator for Until<T, D, F>
+where
+    T: Coordinate,
+    D: SignedCoordinate,
+    F: FnMut(&Point<T>) -> bool
+{
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.blocker.is_some() {
+            return None;
+        }
+        let point = self.ray.next()?;
+        if (self.predicate)(&point) {
+            self.blocker = Some(point);
+            return None;
+        }
+        Some(point)
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{Coordinate, Direction, Direction8, Offset, Point, SignedCoordinate};
+
+/// An axis-aligned rectangle of `width` by `height` points, with `origin` as its top-left point.
+///
+/// Bounds are half-open: a point is within the rect when `origin.x <= x < origin.x + width`,
+/// and likewise for `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T = usize> {
+    pub origin: Point<T>,
+    pub width: usize,
+    pub height: usize
+}
+
+impl Rect {
+    /// A rect with its origin at `(0, 0)`.
+    pub fn new(width: usize, height: usize) -> Rect {
+        Rect {
+            origin: Point { x: 0, y: 0 },
+            width,
+            height
+        }
+    }
+}
+
+impl<T: Coordinate> Rect<T> {
+    pub fn at(origin: Point<T>, width: usize, height: usize) -> Rect<T> {
+        Rect { origin, width, height }
+    }
+
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// The half-open `(min_x, min_y, end_x, end_y)` bounds.
+    pub(crate) fn extent(&self) -> (i128, i128, i128, i128) {
+        let (x, y) = (self.origin.x.to_i128(), self.origin.y.to_i128());
+        (x, y, x + self.width as i128, y + self.height as i128)
+    }
+
+    fn from_extent(min_x: i128, min_y: i128, end_x: i128, end_y: i128) -> Option<Rect<T>> {
+        Some(Rect {
+            origin: Point {
+                x: T::from_i128(min_x)?,
+                y: T::from_i128(min_y)?
+            },
+            width: usize::try_from(end_x - min_x).ok()?,
+            height: usize::try_from(end_y - min_y).ok()?
+        })
+    }
+
+    pub fn contains<U: Coordinate>(&self, point: &Point<U>) -> bool {
+        let (min_x, min_y, end_x, end_y) = self.extent();
+        let (x, y) = (point.x.to_i128(), point.y.to_i128());
+        x >= min_x && y >= min_y && x < end_x && y < end_y
+    }
+
+    /// The top-left, top-right, bottom-right and bottom-left points, or `None` if the rect is empty.
+    pub fn corners(&self) -> Option<[Point<T>; 4]> {
+        if self.is_empty() {
+            return None;
+        }
+        let (min_x, min_y, end_x, end_y) = self.extent();
+        let point = |x: i128, y: i128| Some(Point { x: T::from_i128(x)?, y: T::from_i128(y)? });
+        Some([
+            point(min_x, min_y)?,
+            point(end_x - 1, min_y)?,
+            point(end_x - 1, end_y - 1)?,
+            point(min_x, end_y - 1)?
+        ])
+    }
+
+    /// The points within both rects, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let (a_min_x, a_min_y, a_end_x, a_end_y) = self.extent();
+        let (b_min_x, b_min_y, b_end_x, b_end_y) = other.extent();
+        let rect = Rect::from_extent(
+            a_min_x.max(b_min_x),
+            a_min_y.max(b_min_y),
+            a_end_x.min(b_end_x),
+            a_end_y.min(b_end_y)
+        )?;
+        if rect.is_empty() { None } else { Some(rect) }
+    }
+
+    /// The smallest rect containing both rects, ignoring either one if it is empty.
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        if other.is_empty() {
+            return *self;
+        } else if self.is_empty() {
+            return *other;
+        }
+        let (a_min_x, a_min_y, a_end_x, a_end_y) = self.extent();
+        let (b_min_x, b_min_y, b_end_x, b_end_y) = other.extent();
+        Rect::from_extent(
+            a_min_x.min(b_min_x),
+            a_min_y.min(b_min_y),
+            a_end_x.max(b_end_x),
+            a_end_y.max(b_end_y)
+        ).expect("origin is one of the two rect origins")
+    }
+
+    /// Moves the rect by `offset`, or `None` if its origin doesn't fit in `T`.
+    pub fn translate<D: SignedCoordinate>(&self, offset: &Offset<D>) -> Option<Rect<T>> {
+        Some(Rect {
+            origin: self.origin.checked_add(offset)?,
+            ..*self
+        })
+    }
+
+    /// Grows the rect by `margin` on every side, or `None` if its origin doesn't fit in `T`.
+    pub fn expand(&self, margin: usize) -> Option<Rect<T>> {
+        let margin = margin as i128;
+        let (min_x, min_y, end_x, end_y) = self.extent();
+        Rect::from_extent(min_x - margin, min_y - margin, end_x + margin, end_y + margin)
+    }
+
+    /// The point within the rect closest to `point`.
+    ///
+    /// Panics if the rect is empty.
+    pub fn clamp(&self, point: Point<T>) -> Point<T> {
+        assert!(!self.is_empty(), "cannot clamp to an empty rect");
+        let (min_x, min_y, end_x, end_y) = self.extent();
+        let clamp = |v: T, min: i128, end: i128| {
+            T::from_i128(v.to_i128().clamp(min, end - 1)).expect("clamped value is within the rect")
+        };
+        Point {
+            x: clamp(point.x, min_x, end_x),
+            y: clamp(point.y, min_y, end_y)
+        }
+    }
+
+    /// The up, right, down and left neighbours of `point` that lie within the rect.
+    pub fn neighbors4<U: Coordinate>(&self, point: Point<U>) -> impl Iterator<Item = Point<U>> {
+        let rect = *self;
+        Direction::Up.into_iter()
+            .filter_map(move |direction| point.checked_add(&direction.offset()))
+            .filter(move |neighbor| rect.contains(neighbor))
+    }
+
+    /// The neighbours of `point` in all eight directions that lie within the rect.
+    pub fn neighbors8<U: Coordinate>(&self, point: Point<U>) -> impl Iterator<Item = Point<U>> {
+        let rect = *self;
+        Direction8::Up.into_iter()
+            .filter_map(move |direction| point.checked_add(&direction.offset()))
+            .filter(move |neighbor| rect.contains(neighbor))
+    }
+}
+
+impl<T: Coordinate> Rect<T> {
+    /// The point `x` columns right and `y` rows down from the origin.
+    fn local(&self, x: usize, y: usize) -> Point<T> {
+        Point {
+            x: T::from_i128(self.origin.x.to_i128() + x as i128).expect("point within rect fits in T"),
+            y: T::from_i128(self.origin.y.to_i128() + y as i128).expect("point within rect fits in T")
+        }
+    }
+
+    /// Every point in row-major order, the same as iterating the rect itself.
+    pub fn points(&self) -> RectIterator<T> {
+        self.into_iter()
+    }
+
+    /// Every point in column-major order.
+    pub fn column_major(&self) -> impl Iterator<Item = Point<T>> {
+        self.columns().flatten()
+    }
+
+    /// Each row, top to bottom, as an iterator of its points from left to right.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let rect = *self;
+        (0..rect.height).map(move |y| (0..rect.width).map(move |x| rect.local(x, y)))
+    }
+
+    /// Each column, left to right, as an iterator of its points from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let rect = *self;
+        (0..rect.width).map(move |x| (0..rect.height).map(move |y| rect.local(x, y)))
+    }
+
+    /// Each line running down and to the right, starting from the bottom-left corner and
+    /// ending at the top-right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let rect = *self;
+        let starts = (1..rect.height).rev().map(|y| (0, y))
+            .chain((0..rect.width).map(|x| (x, 0)))
+            .take(rect.line_count());
+        starts.map(move |(x, y)| {
+            let length = (rect.width - x).min(rect.height - y);
+            (0..length).map(move |i| rect.local(x + i, y + i))
+        })
+    }
+
+    /// Each line running down and to the left, starting from the top-left corner and
+    /// ending at the bottom-right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = Point<T>>> {
+        let rect = *self;
+        let starts = (0..rect.width).map(|x| (x, 0))
+            .chain((1..rect.height).map(move |y| (rect.width - 1, y)))
+            .take(rect.line_count());
+        starts.map(move |(x, y)| {
+            let length = (x + 1).min(rect.height - y);
+            (0..length).map(move |i| rect.local(x - i, y + i))
+        })
+    }
+
+    /// The number of diagonal lines crossing the rect.
+    fn line_count(&self) -> usize {
+        if self.is_empty() { 0 } else { self.width + self.height - 1 }
+    }
+
+    /// Every point, spiralling clockwise inwards from the top-left corner.
+    pub fn spiral(&self) -> impl Iterator<Item = Point<T>> {
+        let rect = *self;
+        let layers = rect.width.min(rect.height).div_ceil(2);
+        (0..layers).flat_map(move |layer| {
+            let (left, top) = (layer, layer);
+            let (right, bottom) = (rect.width - 1 - layer, rect.height - 1 - layer);
+
+            let top_row = (left..=right).map(move |x| (x, top));
+            let right_column = (top + 1..=bottom).map(move |y| (right, y));
+            let bottom_row = (left..right).rev()
+                .filter(move |_| bottom > top)
+                .map(move |x| (x, bottom));
+            let left_column = (top + 1..bottom).rev()
+                .filter(move |_| right > left)
+                .map(move |y| (left, y));
+            top_row.chain(right_column).chain(bottom_row).chain(left_column)
+        }).map(move |(x, y)| rect.local(x, y))
+    }
+}
+
+/// Maps points between orientations of a rect. Rotating or transposing swaps `width` and
+/// `height` around the same origin, see [`Rect::transposed`].
+impl<T: Coordinate> Rect<T> {
+    /// The rect with its width and height swapped, keeping the same origin.
+    pub fn transposed(&self) -> Rect<T> {
+        Rect {
+            origin: self.origin,
+            width: self.height,
+            height: self.width
+        }
+    }
+
+    /// The columns right and rows down from the origin to `point`, or `None` if it is outside.
+    fn position(&self, point: Point<T>) -> Option<(usize, usize)> {
+        if !self.contains(&point) {
+            return None;
+        }
+        let (min_x, min_y, _, _) = self.extent();
+        Some(((point.x.to_i128() - min_x) as usize, (point.y.to_i128() - min_y) as usize))
+    }
+
+    /// Where `point` lands once the rect is turned a quarter clockwise, or `None` if it is outside.
+    pub fn rotate_cw_point(&self, point: Point<T>) -> Option<Point<T>> {
+        let (x, y) = self.position(point)?;
+        Some(self.transposed().local(self.height - 1 - y, x))
+    }
+
+    /// Where `point` lands once the rect is turned a quarter anticlockwise, or `None` if it is outside.
+    pub fn rotate_ccw_point(&self, point: Point<T>) -> Option<Point<T>> {
+        let (x, y) = self.position(point)?;
+        Some(self.transposed().local(y, self.width - 1 - x))
+    }
+
+    /// Where `point` lands once the rect is mirrored left to right, or `None` if it is outside.
+    pub fn flip_horizontal_point(&self, point: Point<T>) -> Option<Point<T>> {
+        let (x, y) = self.position(point)?;
+        Some(self.local(self.width - 1 - x, y))
+    }
+
+    /// Where `point` lands once the rect is mirrored top to bottom, or `None` if it is outside.
+    pub fn flip_vertical_point(&self, point: Point<T>) -> Option<Point<T>> {
+        let (x, y) = self.position(point)?;
+        Some(self.local(x, self.height - 1 - y))
+    }
+
+    /// Where `point` lands once the rect is mirrored along its main diagonal, or `None` if it
+    /// is outside.
+    pub fn transpose_point(&self, point: Point<T>) -> Option<Point<T>> {
+        let (x, y) = self.position(point)?;
+        Some(self.transposed().local(y, x))
+    }
+}
+
+/// Iterates points in row-major order, from either end.
+impl<T: Coordinate> IntoIterator for Rect<T> {
+    type Item = Point<T>;
+    type IntoIter = RectIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RectIterator {
+            rect: self,
+            front: 0,
+            back: self.area()
+        }
+    }
+}
+
+pub struct RectIterator<T = usize> {
+    rect: Rect<T>,
+    front: usize,
+    back: usize
+}
+
+impl<T: Coordinate> Iterator for RectIterator<T> {
+    type Item = Point<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Some(self.rect.local(index % self.rect.width, index / self.rect.width))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Coordinate> DoubleEndedIterator for RectIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.rect.local(self.back % self.rect.width, self.back / self.rect.width))
+    }
+}
+
+impl<T: Coordinate> ExactSizeIterator for RectIterator<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xy(points: impl Iterator<Item = Point>) -> Vec<(usize, usize)> {
+        points.map(|point| (point.x, point.y)).collect()
+    }
+
+    fn lines(lines: impl Iterator<Item = impl Iterator<Item = Point>>) -> Vec<Vec<(usize, usize)>> {
+        lines.map(xy).collect()
+    }
+
+    #[test]
+    fn spirals_inwards() {
+        assert_eq!(xy(Rect::new(1, 1).spiral()), [(0, 0)]);
+        assert_eq!(xy(Rect::new(3, 1).spiral()), [(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(xy(Rect::new(1, 3).spiral()), [(0, 0), (0, 1), (0, 2)]);
+        assert_eq!(
+            xy(Rect::new(3, 3).spiral()),
+            [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            xy(Rect::new(4, 3).spiral()),
+            [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (2, 2), (1, 2), (0, 2), (0, 1), (1, 1), (2, 1)]
+        );
+        assert_eq!(
+            xy(Rect::new(2, 5).spiral()),
+            [(0, 0), (1, 0), (1, 1), (1, 2), (1, 3), (1, 4), (0, 4), (0, 3), (0, 2), (0, 1)]
+        );
+        assert_eq!(xy(Rect::at(Point { x: 5, y: 7 }, 2, 2).spiral()), [(5, 7), (6, 7), (6, 8), (5, 8)]);
+    }
+
+    #[test]
+    fn spirals_visit_every_point_once() {
+        for (width, height) in [(5, 4), (4, 5), (6, 2), (7, 7)] {
+            let rect = Rect::new(width, height);
+            let mut spiral = xy(rect.spiral());
+            spiral.sort_by_key(|(x, y)| (*y, *x));
+            assert_eq!(spiral, xy(rect.points()), "{width}x{height}");
+        }
+    }
+
+    #[test]
+    fn diagonals_from_corner_to_corner() {
+        let rect = Rect::new(3, 2);
+        assert_eq!(lines(rect.diagonals()), [vec![(0, 1)], vec![(0, 0), (1, 1)], vec![(1, 0), (2, 1)], vec![(2, 0)]]);
+        assert_eq!(lines(rect.anti_diagonals()), [vec![(0, 0)], vec![(1, 0), (0, 1)], vec![(2, 0), (1, 1)], vec![(2, 1)]]);
+        assert_eq!(lines(Rect::new(1, 3).diagonals()), [vec![(0, 2)], vec![(0, 1)], vec![(0, 0)]]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let rect = Rect::at(Point { x: 1, y: 1 }, 2, 2);
+        assert_eq!(lines(rect.rows()), [vec![(1, 1), (2, 1)], vec![(1, 2), (2, 2)]]);
+        assert_eq!(lines(rect.columns()), [vec![(1, 1), (1, 2)], vec![(2, 1), (2, 2)]]);
+        assert_eq!(xy(rect.column_major()), [(1, 1), (1, 2), (2, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn empty_rects_have_no_lines() {
+        for rect in [Rect::new(0, 3), Rect::new(3, 0), Rect::new(0, 0)] {
+            assert_eq!(rect.spiral().count(), 0);
+            assert_eq!(rect.diagonals().count(), 0);
+            assert_eq!(rect.anti_diagonals().count(), 0);
+            assert_eq!(rect.rows().flatten().count(), 0);
+            assert_eq!(rect.columns().flatten().count(), 0);
+        }
+    }
+    #[test]
+    fn maps_points_between_orientations() {
+        let rect = Rect::new(2, 3);
+        let (top_left, bottom_right) = (Point { x: 0, y: 0 }, Point { x: 1, y: 2 });
+        assert_eq!(rect.rotate_cw_point(top_left), Some(Point { x: 2, y: 0 }));
+        assert_eq!(rect.rotate_cw_point(bottom_right), Some(Point { x: 0, y: 1 }));
+        assert_eq!(rect.rotate_ccw_point(top_left), Some(Point { x: 0, y: 1 }));
+        assert_eq!(rect.rotate_ccw_point(bottom_right), Some(Point { x: 2, y: 0 }));
+        assert_eq!(rect.flip_horizontal_point(top_left), Some(Point { x: 1, y: 0 }));
+        assert_eq!(rect.flip_vertical_point(top_left), Some(Point { x: 0, y: 2 }));
+        assert_eq!(rect.transpose_point(bottom_right), Some(Point { x: 2, y: 1 }));
+        assert_eq!(rect.rotate_cw_point(Point { x: 2, y: 0 }), None);
+
+        for point in rect.points() {
+            let turned = rect.rotate_cw_point(point).unwrap();
+            assert_eq!(rect.transposed().rotate_ccw_point(turned), Some(point));
+        }
+    }
+
+    #[test]
+    fn maps_points_around_an_origin() {
+        let rect = Rect::at(Point { x: 10, y: 20 }, 2, 3);
+        assert_eq!(rect.rotate_cw_point(Point { x: 10, y: 20 }), Some(Point { x: 12, y: 20 }));
+        assert_eq!(rect.transpose_point(Point { x: 11, y: 22 }), Some(Point { x: 12, y: 21 }));
+        assert_eq!(Rect::new(0, 3).rotate_cw_point(Point { x: 0, y: 0 }), None);
+    }
+}
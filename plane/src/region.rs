@@ -0,0 +1,223 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Grid, Point, Rect};
+
+impl<T> Grid<T> {
+    /// Groups cells into regions of up, right, down and left neighbours for which
+    /// `same(a, b)` holds.
+    pub fn regions<F>(&self, same: F) -> Regions where F: FnMut(&T, &T) -> bool {
+        self.label(same, |bounds, point| bounds.neighbors4(point).collect())
+    }
+
+    /// Groups cells into regions of neighbours in all eight directions for which
+    /// `same(a, b)` holds.
+    pub fn regions8<F>(&self, same: F) -> Regions where F: FnMut(&T, &T) -> bool {
+        self.label(same, |bounds, point| bounds.neighbors8(point).collect())
+    }
+
+    fn label<F, N>(&self, mut same: F, neighbors: N) -> Regions
+    where
+        F: FnMut(&T, &T) -> bool,
+        N: Fn(Rect, Point) -> Vec<Point>
+    {
+        let bounds = self.bounds();
+        let mut labels: Grid<Option<usize>> = Grid::filled(bounds, None);
+        let mut regions = Vec::new();
+        for seed in bounds.points() {
+            if labels[seed].is_some() {
+                continue;
+            }
+            let label = regions.len();
+            labels[seed] = Some(label);
+            let mut points = Vec::new();
+            let mut pending = vec![seed];
+            while let Some(point) = pending.pop() {
+                points.push(point);
+                for neighbor in neighbors(bounds, point) {
+                    if labels[neighbor].is_none() && same(&self[point], &self[neighbor]) {
+                        labels[neighbor] = Some(label);
+                        pending.push(neighbor);
+                    }
+                }
+            }
+            regions.push(Region::new(points));
+        }
+        Regions {
+            labels: labels.map(|label| label.expect("every cell is labelled")),
+            regions
+        }
+    }
+}
+
+/// The connected regions of a grid, numbered in the row-major order of their first cell.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>
+}
+
+impl Regions {
+    /// The region number of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// The region containing `point`, panicking if it is outside the grid.
+    pub fn at(&self, point: Point) -> &Region {
+        &self.regions[self.labels[point]]
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Region> {
+        self.regions.get(label)
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = std::slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.regions.iter()
+    }
+}
+
+/// The side of `point` facing `side`, where the region borders a cell outside of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub point: Point,
+    pub side: Direction
+}
+
+/// A set of connected cells.
+#[derive(Debug, Clone)]
+pub struct Region {
+    points: Vec<Point>,
+    members: HashSet<Point>
+}
+
+impl Region {
+    fn new(mut points: Vec<Point>) -> Region {
+        points.sort_by_key(|point| (point.y, point.x));
+        let members = points.iter().copied().collect();
+        Region { points, members }
+    }
+
+    /// The region's points in row-major order.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.members.contains(point)
+    }
+
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// The smallest rect containing every point of the region.
+    pub fn bounds(&self) -> Rect {
+        self.points.iter()
+            .map(|point| Rect::at(*point, 1, 1))
+            .fold(Rect::new(0, 0), |bounds, cell| bounds.union(&cell))
+    }
+
+    /// Every unit edge between the region and the outside, including edges facing holes.
+    pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        self.points.iter().flat_map(move |point| {
+            Direction::Up.into_iter()
+                .filter(move |side| !self.has_neighbor(point, *side))
+                .map(move |side| Edge { point: *point, side })
+        })
+    }
+
+    /// The number of unit edges, see [`Region::edges`].
+    pub fn perimeter(&self) -> usize {
+        self.edges().count()
+    }
+
+    /// The number of straight sides, where a run of edges facing the same way along a line
+    /// counts once.
+    pub fn sides(&self) -> usize {
+        self.edges()
+            .filter(|edge| {
+                // An edge starts a side unless the edge before it continues the same side.
+                let before = edge.side.rotate_ccw();
+                match edge.point.checked_add(&before.offset()) {
+                    Some(previous) if self.contains(&previous) => self.has_neighbor(&previous, edge.side),
+                    _ => true
+                }
+            })
+            .count()
+    }
+
+    fn has_neighbor(&self, point: &Point, side: Direction) -> bool {
+        point.checked_add(&side.offset()).is_some_and(|neighbor| self.contains(&neighbor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn garden(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+    }
+
+    fn prices(rows: &[&str]) -> (usize, usize) {
+        let regions = garden(rows).regions(|a, b| a == b);
+        regions.iter()
+            .map(|region| (region.area() * region.perimeter(), region.area() * region.sides()))
+            .fold((0, 0), |(total, discounted), (price, discount)| (total + price, discounted + discount))
+    }
+
+    #[test]
+    fn small_garden() {
+        let regions = garden(&["AAAA", "BBCD", "BBCC", "EEEC"]).regions(|a, b| a == b);
+        assert_eq!(regions.len(), 5);
+        let c = regions.at(Point { x: 2, y: 1 });
+        assert_eq!((c.area(), c.perimeter(), c.sides()), (4, 10, 8));
+        assert_eq!(c.bounds(), Rect::at(Point { x: 2, y: 1 }, 2, 3));
+        assert_eq!(regions.labels()[Point { x: 3, y: 3 }], regions.labels()[Point { x: 2, y: 1 }]);
+        assert_eq!(prices(&["AAAA", "BBCD", "BBCC", "EEEC"]), (140, 80));
+    }
+
+    #[test]
+    fn sides_of_an_e() {
+        assert_eq!(prices(&["EEEEE", "EXXXX", "EEEEE", "EXXXX", "EEEEE"]).1, 236);
+    }
+
+    #[test]
+    fn sides_facing_holes() {
+        let rows = ["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"];
+        let regions = garden(&rows).regions(|a, b| a == b);
+        let a = regions.at(Point { x: 0, y: 0 });
+        assert_eq!((a.area(), a.sides()), (28, 12));
+        assert_eq!(prices(&rows).1, 368);
+    }
+
+    #[test]
+    fn regions8_joins_diagonals() {
+        let grid = garden(&["#..", ".#.", "..#"]);
+        let same = |a: &char, b: &char| a == b;
+        assert_eq!(grid.regions(same).len(), 5);
+        let regions = grid.regions8(same);
+        assert_eq!(regions.len(), 2);
+        let wall = regions.at(Point { x: 1, y: 1 });
+        assert_eq!(wall.points(), [Point { x: 0, y: 0 }, Point { x: 1, y: 1 }, Point { x: 2, y: 2 }]);
+        assert_eq!(wall.perimeter(), 12);
+        assert_eq!(regions.at(Point { x: 2, y: 0 }).area(), 6);
+    }
+}
@@ -0,0 +1,463 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::{Direction, Grid, Point, Turn};
+
+/// The cheapest way found from a start state to the goal, keeping every predecessor that ties
+/// so all equally short paths can be recovered.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    start: S,
+    cost: u64,
+    goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// Every goal state reached at the shortest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One shortest path, from the start to the first goal found, both included.
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goals[0].clone()];
+        while let Some(previous) = self.previous(path.last().expect("path is never empty")).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// Every shortest path to every goal, never visiting a state twice, so zero-cost loops
+    /// between tied states are walked at most once. There can be exponentially many of them,
+    /// prefer [`ShortestPaths::states`] when only the states matter.
+    pub fn all_paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        for goal in &self.goals {
+            self.collect_paths(&mut vec![goal.clone()], &mut HashSet::from([goal.clone()]), &mut paths);
+        }
+        paths
+    }
+
+    /// Every state lying on at least one shortest path.
+    pub fn states(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut pending = self.goals.clone();
+        while let Some(state) = pending.pop() {
+            for previous in self.previous(&state) {
+                if states.insert(previous.clone()) {
+                    pending.push(previous.clone());
+                }
+            }
+        }
+        states
+    }
+
+    fn previous(&self, state: &S) -> &[S] {
+        if *state == self.start {
+            return &[];
+        }
+        self.predecessors.get(state).map_or(&[], |previous| previous.as_slice())
+    }
+
+    /// Extends `reversed`, a path walked back from a goal through the states in `on_path`, to
+    /// the start in every possible way.
+    fn collect_paths(&self, reversed: &mut Vec<S>, on_path: &mut HashSet<S>, paths: &mut Vec<Vec<S>>) {
+        let last = reversed.last().expect("path is never empty");
+        if *last == self.start {
+            paths.push(reversed.iter().rev().cloned().collect());
+            return;
+        }
+        for state in self.previous(last) {
+            if !on_path.insert(state.clone()) {
+                continue;
+            }
+            reversed.push(state.clone());
+            self.collect_paths(reversed, on_path, paths);
+            reversed.pop();
+            on_path.remove(state);
+        }
+    }
+}
+
+/// Breadth-first search where every move costs one, or `None` if no goal can be reached.
+pub fn bfs<S, F, I, G>(start: S, mut successors: F, is_goal: G) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool
+{
+    dijkstra(start, |state| successors(state).into_iter().map(|next| (next, 1)), is_goal)
+}
+
+/// Dijkstra's search over moves yielded as `(state, cost)`, or `None` if no goal can be reached.
+pub fn dijkstra<S, F, I, G>(start: S, successors: F, is_goal: G) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search, guided by a `heuristic` that must never overestimate the remaining cost and
+/// never drop by more than the cost of a move. Returns `None` if no goal can be reached.
+pub fn astar<S, F, I, H, G>(start: S, mut successors: F, mut heuristic: H, mut is_goal: G) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool
+{
+    let mut best: HashMap<S, u64> = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut expanded: HashSet<S> = HashSet::new();
+    let mut frontier = BinaryHeap::from([Candidate { priority: heuristic(&start), cost: 0, state: start.clone() }]);
+    let mut goals = Vec::new();
+    let mut goal_cost = None;
+
+    while let Some(Candidate { priority, cost, state }) = frontier.pop() {
+        if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+            break;
+        }
+        if !expanded.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match best.get(&next) {
+                Some(&known) if next_cost > known => {}
+                Some(&known) if next_cost == known => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    best.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    let priority = next_cost + heuristic(&next);
+                    frontier.push(Candidate { priority, cost: next_cost, state: next });
+                }
+            }
+        }
+    }
+
+    Some(ShortestPaths {
+        start,
+        cost: goal_cost?,
+        goals,
+        predecessors
+    })
+}
+
+/// A frontier entry, ordered so the heap pops the lowest priority first.
+struct Candidate<S> {
+    priority: u64,
+    cost: u64,
+    state: S
+}
+
+impl<S> PartialEq for Candidate<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Candidate<S> {}
+
+impl<S> PartialOrd for Candidate<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Candidate<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Successors for [`bfs`] over the points of a grid, stepping to a neighbour whenever
+/// `passable(from, to)` holds for their cells.
+pub struct GridMoves<'a, T, F> {
+    grid: &'a Grid<T>,
+    diagonal: bool,
+    passable: F
+}
+
+impl<'a, T, F> GridMoves<'a, T, F> where F: FnMut(&T, &T) -> bool {
+    /// Moves up, right, down and left.
+    pub fn four(grid: &'a Grid<T>, passable: F) -> GridMoves<'a, T, F> {
+        GridMoves { grid, diagonal: false, passable }
+    }
+
+    /// Moves in all eight directions.
+    pub fn eight(grid: &'a Grid<T>, passable: F) -> GridMoves<'a, T, F> {
+        GridMoves { grid, diagonal: true, passable }
+    }
+
+    pub fn successors(&mut self, point: &Point) -> Vec<Point> {
+        let from = &self.grid[*point];
+        let neighbors: Vec<(Point, &T)> = if self.diagonal {
+            self.grid.neighbors8(*point).collect()
+        } else {
+            self.grid.neighbors4(*point).collect()
+        };
+        neighbors.into_iter()
+            .filter(|(_, to)| (self.passable)(from, to))
+            .map(|(neighbor, _)| neighbor)
+            .collect()
+    }
+}
+
+/// Successors for [`dijkstra`] over `(Point, Direction)` states: step forward for `step_cost`
+/// when `passable(from, to)` holds, or turn left or right on the spot for `turn_cost`.
+pub struct TurningMoves<'a, T, F> {
+    grid: &'a Grid<T>,
+    step_cost: u64,
+    turn_cost: u64,
+    passable: F
+}
+
+impl<'a, T, F> TurningMoves<'a, T, F> where F: FnMut(&T, &T) -> bool {
+    pub fn new(grid: &'a Grid<T>, step_cost: u64, turn_cost: u64, passable: F) -> TurningMoves<'a, T, F> {
+        TurningMoves { grid, step_cost, turn_cost, passable }
+    }
+
+    pub fn successors(&mut self, &(point, facing): &(Point, Direction)) -> Vec<((Point, Direction), u64)> {
+        let mut moves = vec![
+            ((point, facing.turn(Turn::Left)), self.turn_cost),
+            ((point, facing.turn(Turn::Right)), self.turn_cost)
+        ];
+        let ahead = point.checked_add(&facing.offset()).filter(|ahead| self.grid.bounds().contains(ahead));
+        if let Some(ahead) = ahead {
+            if (self.passable)(&self.grid[point], &self.grid[ahead]) {
+                moves.push(((ahead, facing), self.step_cost));
+            }
+        }
+        moves
+    }
+}
+
+impl<T> Grid<T> {
+    /// For every cell, the number of paths from it to a cell matching `is_target`, moving to an
+    /// up, right, down or left neighbour whenever `step(from, to)` holds. A path may pass
+    /// through targets, and a target on its own counts as one path.
+    ///
+    /// Each cell is visited once, so this is linear in the size of the grid. Returns `None` if
+    /// the steps form a cycle or a count doesn't fit in a `u64`, which a grid as small as
+    /// 40 by 40 can manage when every step goes right or down.
+    pub fn count_paths<F, G>(&self, step: F, mut is_target: G) -> Option<Grid<u64>>
+    where
+        F: FnMut(&T, &T) -> bool,
+        G: FnMut(&T) -> bool
+    {
+        self.fold_paths(step, |_, cell| u64::from(is_target(cell)), |count, more| {
+            *count = count.checked_add(*more)?;
+            Some(())
+        })
+    }
+
+    /// For every cell, the targets reachable from it in row-major order, moving as in
+    /// [`Grid::count_paths`]. Returns `None` if the steps form a cycle.
+    pub fn reachable_targets<F, G>(&self, step: F, mut is_target: G) -> Option<Grid<Vec<Point>>>
+    where
+        F: FnMut(&T, &T) -> bool,
+        G: FnMut(&T) -> bool
+    {
+        self.fold_paths(
+            step,
+            |point, cell| if is_target(cell) { vec![point] } else { Vec::new() },
+            |targets, more| {
+                targets.extend_from_slice(more);
+                targets.sort_by_key(|point| (point.y, point.x));
+                targets.dedup();
+                Some(())
+            }
+        )
+    }
+
+    /// Starts each cell at `init` and folds in the value of every cell one step away, once
+    /// that cell has folded in everything beyond it. Gives up with `None` as soon as `combine`
+    /// does.
+    fn fold_paths<U, F, I, C>(&self, mut step: F, mut init: I, mut combine: C) -> Option<Grid<U>>
+    where
+        F: FnMut(&T, &T) -> bool,
+        I: FnMut(Point, &T) -> U,
+        C: FnMut(&mut U, &U) -> Option<()>
+    {
+        let bounds = self.bounds();
+        let mut unfolded = self.map(|_| 0);
+        let mut values: Vec<Option<U>> = Vec::with_capacity(bounds.area());
+        for (point, cell) in self.iter() {
+            unfolded[point] = self.neighbors4(point).filter(|(_, next)| step(cell, next)).count();
+            values.push(Some(init(point, cell)));
+        }
+        let mut values = Grid::new(bounds, values).expect("one value per cell");
+
+        let mut ready: Vec<Point> = unfolded.iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(point, _)| point)
+            .collect();
+        let mut folded = 0;
+        while let Some(point) = ready.pop() {
+            folded += 1;
+            let value = values[point].take().expect("each cell is folded once");
+            for (previous, cell) in self.neighbors4(point) {
+                if !step(cell, &self[point]) {
+                    continue;
+                }
+                combine(values[previous].as_mut().expect("cell is folded after its successors"), &value)?;
+                unfolded[previous] -= 1;
+                if unfolded[previous] == 0 {
+                    ready.push(previous);
+                }
+            }
+            values[point] = Some(value);
+        }
+        if folded < bounds.area() {
+            return None;
+        }
+        let values = values.iter_mut().map(|(_, value)| value.take().expect("every cell is folded")).collect();
+        Grid::new(bounds, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rect;
+
+    /// Moves along `edges`, given as `(from, to, cost)`.
+    fn moves(edges: &[(u32, u32, u64)]) -> impl FnMut(&u32) -> Vec<(u32, u64)> + '_ {
+        move |state| edges.iter()
+            .filter(|(from, _, _)| from == state)
+            .map(|(_, to, cost)| (*to, *cost))
+            .collect()
+    }
+
+    fn sorted(mut paths: Vec<Vec<u32>>) -> Vec<Vec<u32>> {
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn bfs_counts_moves() {
+        let found = bfs(0, |n: &u32| vec![n + 1, n * 2], |n| *n == 10).unwrap();
+        assert_eq!(found.cost(), 5);
+        assert_eq!(found.path().len(), 6);
+        assert_eq!(found.path().first(), Some(&0));
+        assert_eq!(found.path().last(), Some(&10));
+    }
+
+    #[test]
+    fn bfs_without_a_goal() {
+        assert!(bfs(0, |n: &u32| if *n < 5 { vec![n + 1] } else { vec![] }, |n| *n == 10).is_none());
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detours() {
+        let edges = [(0, 3, 10), (0, 1, 1), (1, 2, 1), (2, 3, 1)];
+        let found = dijkstra(0, moves(&edges), |n| *n == 3).unwrap();
+        assert_eq!(found.cost(), 3);
+        assert_eq!(found.path(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_on_a_grid() {
+        let grid = Grid::from_rows(["....#", ".##.#", "...#.", ".#...", "....."]
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect()).unwrap();
+        let (start, goal) = (Point { x: 0, y: 0 }, Point { x: 4, y: 2 });
+        let mut grid_moves = GridMoves::four(&grid, |_, to: &char| *to != '#');
+        let unit_moves = |point: &Point| grid_moves.successors(point).into_iter().map(|next| (next, 1)).collect::<Vec<_>>();
+        let found = astar(start, unit_moves, |point| point.manhattan_distance(&goal), |point| *point == goal).unwrap();
+
+        let mut grid_moves = GridMoves::four(&grid, |_, to: &char| *to != '#');
+        let expected = bfs(start, |point| grid_moves.successors(point), |point| *point == goal).unwrap();
+        assert_eq!(found.cost(), 8);
+        assert_eq!(found.cost(), expected.cost());
+        assert_eq!(found.states(), expected.states());
+    }
+
+    #[test]
+    fn ties_keep_every_shortest_path() {
+        let edges = [(0, 1, 1), (0, 2, 1), (1, 3, 1), (2, 3, 1), (3, 4, 1), (0, 4, 5)];
+        let found = dijkstra(0, moves(&edges), |n| *n == 4).unwrap();
+        assert_eq!(found.cost(), 3);
+        assert_eq!(sorted(found.all_paths()), [vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+        assert_eq!(found.states(), HashSet::from([0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn all_paths_through_zero_cost_ties() {
+        let edges = [(0, 1, 1), (1, 2, 0), (2, 1, 0), (2, 3, 1)];
+        let found = dijkstra(0, moves(&edges), |n| *n == 3).unwrap();
+        assert_eq!(found.cost(), 2);
+        assert_eq!(found.path(), [0, 1, 2, 3]);
+        assert_eq!(found.all_paths(), [vec![0, 1, 2, 3]]);
+        assert_eq!(found.states(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn turning_costs_extra() {
+        let grid = Grid::from_rows(["...", ".#.", "..."].iter().map(|row| row.chars().collect()).collect()).unwrap();
+        let mut turning = TurningMoves::new(&grid, 1, 1000, |_, to: &char| *to != '#');
+        let start = (Point { x: 0, y: 2 }, Direction::Right);
+        let found = dijkstra(start, |state| turning.successors(state), |(point, _)| *point == Point { x: 2, y: 0 }).unwrap();
+        assert_eq!(found.cost(), 1004);
+        assert_eq!(found.all_paths().len(), 1);
+        assert_eq!(found.path().iter().filter(|(point, _)| *point == Point { x: 2, y: 2 }).count(), 2);
+    }
+
+    fn digits(rows: &[&str]) -> Grid<u32> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().map(|c| c.to_digit(10).unwrap()).collect()).collect()).unwrap()
+    }
+
+    fn climb(from: &u32, to: &u32) -> bool {
+        *to == from + 1
+    }
+
+    #[test]
+    fn trailhead_scores_and_ratings() {
+        let map = digits(&["89010123", "78121874", "87430965", "96549874", "45678903", "32019012", "01329801", "10456732"]);
+        let is_peak = |elevation: &u32| *elevation == 9;
+        let ratings = map.count_paths(climb, is_peak).unwrap();
+        let peaks = map.reachable_targets(climb, is_peak).unwrap();
+        let trailheads: Vec<Point> = map.iter().filter(|(_, elevation)| **elevation == 0).map(|(point, _)| point).collect();
+        assert_eq!(trailheads.iter().map(|point| peaks[*point].len()).sum::<usize>(), 36);
+        assert_eq!(trailheads.iter().map(|point| ratings[*point]).sum::<u64>(), 81);
+        assert_eq!(peaks[Point { x: 2, y: 0 }].len(), 5);
+        assert_eq!(ratings[Point { x: 2, y: 0 }], 20);
+    }
+
+    #[test]
+    fn cyclic_steps_fold_nothing() {
+        let map = digits(&["01", "32"]);
+        let around = |from: &u32, to: &u32| *to == (from + 1) % 4;
+        assert!(map.count_paths(around, |elevation| *elevation == 3).is_none());
+        assert!(map.reachable_targets(around, |elevation| *elevation == 3).is_none());
+    }
+
+    #[test]
+    fn path_counts_that_overflow() {
+        let bounds = Rect::new(200, 200);
+        let gradient = Grid::new(bounds, bounds.points().map(|point| (point.x + point.y) as u32).collect()).unwrap();
+        let at_the_end = |elevation: &u32| *elevation == 398;
+        assert!(gradient.count_paths(climb, at_the_end).is_none());
+        assert_eq!(digits(&["012", "123", "234"]).count_paths(climb, |elevation| *elevation == 4).unwrap()[Point { x: 0, y: 0 }], 6);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{Direction, Grid, Point, Rect, Turn};
+
+/// How a simulation ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome<S> {
+    /// The move rule gave up, with the last state before it did.
+    Exited(S),
+    /// A state came round again, the first state of the loop.
+    Looped(S)
+}
+
+/// The result of [`simulate`]: how it ended, and the number of moves made until it left or
+/// first came back to a state it had already been in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Simulation<S> {
+    pub outcome: Outcome<S>,
+    pub steps: usize
+}
+
+/// Applies `rule` from `start` until it returns `None` or the states repeat.
+///
+/// Loops are found with Brent's algorithm, so no states are stored, but `rule` must always
+/// give the same next state for the same state. Use [`Walker::walk`] instead when the states
+/// passed through are needed afterwards.
+pub fn simulate<S, F>(start: S, mut rule: F) -> Simulation<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = match rule(&start) {
+        Some(next) => next,
+        None => return Simulation { outcome: Outcome::Exited(start), steps: 0 }
+    };
+    let mut steps = 1;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = match rule(&hare) {
+            Some(next) => next,
+            None => return Simulation { outcome: Outcome::Exited(hare), steps }
+        };
+        length += 1;
+        steps += 1;
+    }
+
+    // Walk two states `length` apart from the start until they meet at the loop's first state.
+    let follow = |rule: &mut F, state: &S| rule(state).expect("rule is deterministic");
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = follow(&mut rule, &hare);
+    }
+    let mut lead = 0;
+    while tortoise != hare {
+        tortoise = follow(&mut rule, &tortoise);
+        hare = follow(&mut rule, &hare);
+        lead += 1;
+    }
+    Simulation { outcome: Outcome::Looped(tortoise), steps: lead + length }
+}
+
+/// Something at a point, facing one of the four directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Walker {
+    pub position: Point,
+    pub facing: Direction
+}
+
+impl Walker {
+    pub fn new(position: Point, facing: Direction) -> Walker {
+        Walker { position, facing }
+    }
+
+    /// The point one step ahead, or `None` if it doesn't fit in a `usize`.
+    pub fn ahead(&self) -> Option<Point> {
+        self.position.checked_add(&self.facing.offset())
+    }
+
+    /// The walker one step ahead, or `None` if that point doesn't fit in a `usize`.
+    pub fn stepped(&self) -> Option<Walker> {
+        Some(Walker { position: self.ahead()?, ..*self })
+    }
+
+    pub fn turned(&self, turn: Turn) -> Walker {
+        Walker { facing: self.facing.turn(turn), ..*self }
+    }
+
+    /// Applies `rule` until it returns `None`, the walker leaves `bounds`, or it repeats a
+    /// position and facing, recording every state in a bitmap along the way.
+    ///
+    /// Panics if the walker starts outside `bounds`.
+    pub fn walk<F>(self, bounds: Rect, mut rule: F) -> Walk where F: FnMut(&Walker) -> Option<Walker> {
+        let mut visited: Grid<u8> = Grid::filled(bounds, 0);
+        let mut walker = self;
+        let mut steps = 0;
+        let outcome = loop {
+            let seen = &mut visited[walker.position];
+            if *seen & walker.facing_bit() != 0 {
+                break Outcome::Looped(walker);
+            }
+            *seen |= walker.facing_bit();
+            match rule(&walker).filter(|next| bounds.contains(&next.position)) {
+                Some(next) => walker = next,
+                None => break Outcome::Exited(walker)
+            }
+            steps += 1;
+        };
+        Walk { outcome, steps, visited }
+    }
+
+    fn facing_bit(&self) -> u8 {
+        match self.facing {
+            Direction::Up => 1,
+            Direction::Right => 2,
+            Direction::Down => 4,
+            Direction::Left => 8
+        }
+    }
+}
+
+/// The result of [`Walker::walk`], counting steps as [`Simulation`] does.
+#[derive(Debug, Clone)]
+pub struct Walk {
+    pub outcome: Outcome<Walker>,
+    pub steps: usize,
+    visited: Grid<u8>
+}
+
+impl Walk {
+    /// Whether the walker stood on `point`, facing any direction.
+    pub fn visited(&self, point: Point) -> bool {
+        self.visited.get(point).is_some_and(|seen| *seen != 0)
+    }
+
+    /// Every point the walker stood on, in row-major order.
+    pub fn visited_points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.visited.iter()
+            .filter(|(_, seen)| **seen != 0)
+            .map(|(point, _)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_after_a_tail() {
+        // 0, 1, 2 lead into the loop 3, 4, 5, 6.
+        let found = simulate(0, |n: &u32| Some(if *n < 6 { n + 1 } else { 3 }));
+        assert_eq!(found, Simulation { outcome: Outcome::Looped(3), steps: 7 });
+        let found = simulate(5, |n: &u32| Some(if *n < 6 { n + 1 } else { 3 }));
+        assert_eq!(found, Simulation { outcome: Outcome::Looped(5), steps: 4 });
+    }
+
+    #[test]
+    fn loops_on_the_spot() {
+        assert_eq!(simulate(7, |n: &u32| Some(*n)), Simulation { outcome: Outcome::Looped(7), steps: 1 });
+    }
+
+    #[test]
+    fn exits_after_the_last_move() {
+        assert_eq!(simulate(0, |n: &u32| (*n < 5).then_some(n + 1)), Simulation { outcome: Outcome::Exited(5), steps: 5 });
+        assert_eq!(simulate(0, |_: &u32| None), Simulation { outcome: Outcome::Exited(0), steps: 0 });
+    }
+
+    #[test]
+    fn walk_records_visited_points() {
+        let bounds = Rect::new(3, 3);
+        let start = Walker::new(Point { x: 0, y: 1 }, Direction::Right);
+        let walk = start.walk(bounds, Walker::stepped);
+        assert_eq!(walk.outcome, Outcome::Exited(Walker::new(Point { x: 2, y: 1 }, Direction::Right)));
+        assert_eq!(walk.steps, 2);
+        assert_eq!(walk.visited_points().collect::<Vec<_>>(), [Point { x: 0, y: 1 }, Point { x: 1, y: 1 }, Point { x: 2, y: 1 }]);
+        assert!(!walk.visited(Point { x: 1, y: 0 }));
+
+        let start = Walker::new(Point { x: 0, y: 0 }, Direction::Right);
+        let around = |walker: &Walker| {
+            let next = walker.stepped().filter(|next| bounds.contains(&next.position));
+            next.or_else(|| Some(walker.turned(Turn::Right)))
+        };
+        let walk = start.walk(bounds, around);
+        assert_eq!(walk.outcome, Outcome::Looped(start));
+        assert_eq!(walk.visited_points().count(), 8);
+        assert_eq!(walk.steps, simulate(start, around).steps);
+    }
+}